@@ -0,0 +1,240 @@
+//! Structured error type shared across FFI entry points.
+//!
+//! Before this module existed, every failure on the Rust side - a `NewSessionError`,
+//! a `PrepareError`, a `PagerExecutionError`, a caught panic - was collapsed into a
+//! single `CString` message, so the C# side had no way to distinguish a timeout from
+//! an auth failure from an invalid query. [`BridgedError`] instead carries a stable
+//! [`ErrorCategory`], a Scylla error code field (currently always -1, see
+//! [`ClassifyError`]), and whether the managed driver's retry policy may safely retry
+//! the request.
+
+use std::ffi::{CString, c_char};
+use std::fmt::Display;
+
+/// Stable, FFI-safe classification of a [`BridgedError`].
+///
+/// Numeric values are part of the FFI contract - do not renumber existing variants.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    ConnectionFailed = 0,
+    ReadTimeout = 1,
+    WriteTimeout = 2,
+    Unavailable = 3,
+    InvalidQuery = 4,
+    Serialization = 5,
+    Panic = 6,
+    Cancelled = 7,
+    Other = 8,
+    /// The server reported fewer columns for a row than its own metadata declared.
+    TooFewColumns = 9,
+    /// The result set was already fully consumed. Not produced by
+    /// `row_set_next_row`/`row_set_next_page`, which report exhaustion through their
+    /// return values instead.
+    PagerExhausted = 10,
+    /// A `RowSet`'s internal mutex was poisoned by a panic on another thread.
+    LockPoisoned = 11,
+    /// Deserializing a cell's raw bytes into its declared CQL type failed.
+    Deserialization = 12,
+    /// `pre_serialized_values_add_converted` was given a conversion name that does not
+    /// match any known conversion.
+    UnknownConversion = 13,
+    /// A textual/primitive input could not be converted to its target CQL wire format.
+    ConversionFailed = 14,
+    /// The requested capability is not yet implemented.
+    Unsupported = 15,
+}
+
+/// A structured error surfaced to the C# side.
+///
+/// Owned by whoever receives it from an FFI call; must be released with
+/// [`bridged_error_free`].
+#[repr(C)]
+pub struct BridgedError {
+    category: ErrorCategory,
+    /// Scylla protocol error code slot. Always -1: errors are classified from their
+    /// `Display` text (see [`ClassifyError`]), which does not extract the server's
+    /// `DbError` code, even for server-reported errors.
+    scylla_error_code: i32,
+    /// Non-zero if the managed driver's retry policy may safely retry this request.
+    retryable: i32,
+    /// Owned, NUL-terminated UTF-8 message. Freed together with the `BridgedError`.
+    message: *mut c_char,
+}
+
+impl BridgedError {
+    fn new(category: ErrorCategory, scylla_error_code: i32, retryable: bool, message: impl Display) -> Self {
+        let message = CString::new(message.to_string())
+            .unwrap_or_else(|_| CString::new("<error message contained a NUL byte>").unwrap());
+        BridgedError {
+            category,
+            scylla_error_code,
+            retryable: retryable as i32,
+            message: message.into_raw(),
+        }
+    }
+
+    pub(crate) fn panic(message: impl Display) -> Self {
+        Self::new(ErrorCategory::Panic, -1, false, message)
+    }
+
+    pub(crate) fn cancelled() -> Self {
+        Self::new(ErrorCategory::Cancelled, -1, false, "the task was cancelled")
+    }
+
+    /// Builds a `BridgedError` from any Scylla driver error by classifying it via
+    /// [`ClassifyError`] and keeping its `Display` text as the message.
+    pub(crate) fn from_scylla_error(err: &(impl ClassifyError + Display)) -> Self {
+        let (category, scylla_error_code, retryable) = err.classify();
+        Self::new(category, scylla_error_code, retryable, err)
+    }
+
+    pub(crate) fn too_few_columns() -> Self {
+        Self::new(
+            ErrorCategory::TooFewColumns,
+            -1,
+            false,
+            "server provided fewer columns than claimed in the row metadata",
+        )
+    }
+
+    pub(crate) fn lock_poisoned() -> Self {
+        Self::new(
+            ErrorCategory::LockPoisoned,
+            -1,
+            false,
+            "the row set's internal lock was poisoned by a panicking thread",
+        )
+    }
+
+    pub(crate) fn deserialization_failed(message: impl Display) -> Self {
+        Self::new(ErrorCategory::Deserialization, -1, false, message)
+    }
+
+    pub(crate) fn unknown_conversion(name: &str) -> Self {
+        Self::new(
+            ErrorCategory::UnknownConversion,
+            -1,
+            false,
+            format!("unknown conversion name '{name}'"),
+        )
+    }
+
+    pub(crate) fn conversion_failed(message: impl Display) -> Self {
+        Self::new(ErrorCategory::ConversionFailed, -1, false, message)
+    }
+
+    pub(crate) fn unsupported(message: impl Display) -> Self {
+        Self::new(ErrorCategory::Unsupported, -1, false, message)
+    }
+}
+
+/// A trivial `Display`-only error for code paths that must report failure through the
+/// `BridgedError`/`Tcb` machinery but have nothing real to attempt yet.
+#[derive(Debug)]
+pub(crate) struct NotYetSupported(pub(crate) &'static str);
+
+impl Display for NotYetSupported {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not yet supported: {}", self.0)
+    }
+}
+
+/// Boxes `err` and writes it through `out_error`, if the caller provided one.
+///
+/// Shared by every FFI entry point that reports failures via an
+/// `*mut *mut BridgedError` out-parameter instead of a `Result`.
+pub(crate) fn set_out_error(out_error: *mut *mut BridgedError, err: BridgedError) {
+    if !out_error.is_null() {
+        unsafe { *out_error = Box::into_raw(Box::new(err)) };
+    }
+}
+
+/// Classifies a Scylla driver error into a stable [`ErrorCategory`], a Scylla error
+/// code (always -1 from the default implementation below), and whether the request is
+/// safe to retry.
+///
+/// The Scylla driver's error enums (`NewSessionError`, `PrepareError`,
+/// `PagerExecutionError`, ...) are deep and version-sensitive, so rather than
+/// pattern-matching on their exact variant shapes (which would need updating every
+/// time the driver restructures an error type), classification is done by inspecting
+/// the `Display` text for well-known substrings that the driver renders consistently.
+/// This is coarser than matching on `DbError` directly, but it keeps working across
+/// driver upgrades. Tightening this to match on the concrete error variants is a
+/// reasonable follow-up once we pin a driver version.
+pub(crate) trait ClassifyError: Display {
+    fn classify(&self) -> (ErrorCategory, i32, bool) {
+        let text = self.to_string();
+        let lower = text.to_ascii_lowercase();
+
+        if lower.contains("unavailable") {
+            (ErrorCategory::Unavailable, -1, true)
+        } else if lower.contains("read timeout") || lower.contains("readtimeout") {
+            (ErrorCategory::ReadTimeout, -1, true)
+        } else if lower.contains("write timeout") || lower.contains("writetimeout") {
+            (ErrorCategory::WriteTimeout, -1, true)
+        } else if lower.contains("overloaded") || lower.contains("bootstrapping") {
+            (ErrorCategory::Unavailable, -1, true)
+        } else if lower.contains("syntax") || lower.contains("invalid") || lower.contains("unprepared") {
+            (ErrorCategory::InvalidQuery, -1, false)
+        } else if lower.contains("unauthorized") || lower.contains("authentication") {
+            (ErrorCategory::InvalidQuery, -1, false)
+        } else if lower.contains("connect") || lower.contains("resolve") || lower.contains("io error") {
+            (ErrorCategory::ConnectionFailed, -1, true)
+        } else if lower.contains("serializ") || lower.contains("deserializ") {
+            (ErrorCategory::Serialization, -1, false)
+        } else if lower.contains("not yet supported") {
+            (ErrorCategory::Unsupported, -1, false)
+        } else {
+            (ErrorCategory::Other, -1, false)
+        }
+    }
+}
+
+impl<E: Display> ClassifyError for E {}
+
+/// Frees a [`BridgedError`] previously returned across the FFI boundary.
+#[unsafe(no_mangle)]
+pub extern "C" fn bridged_error_free(error: *mut BridgedError) {
+    if error.is_null() {
+        return;
+    }
+    unsafe {
+        let err = Box::from_raw(error);
+        drop(CString::from_raw(err.message));
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn bridged_error_get_category(error: *const BridgedError) -> i32 {
+    if error.is_null() {
+        return ErrorCategory::Other as i32;
+    }
+    unsafe { (*error).category as i32 }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn bridged_error_get_scylla_code(error: *const BridgedError) -> i32 {
+    if error.is_null() {
+        return -1;
+    }
+    unsafe { (*error).scylla_error_code }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn bridged_error_is_retryable(error: *const BridgedError) -> i32 {
+    if error.is_null() {
+        return 0;
+    }
+    unsafe { (*error).retryable }
+}
+
+/// Returns a pointer to the error's NUL-terminated UTF-8 message.
+/// Valid until the `BridgedError` is freed via [`bridged_error_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn bridged_error_get_message(error: *const BridgedError) -> *const c_char {
+    if error.is_null() {
+        return std::ptr::null();
+    }
+    unsafe { (*error).message }
+}
@@ -1,5 +1,6 @@
 use std::ffi::c_void;
 use std::marker::PhantomData;
+use std::ops::Deref;
 use std::ptr::NonNull;
 use std::sync::{Arc, Weak};
 
@@ -503,6 +504,254 @@ pub struct FromRef;
 impl<T> origin_sealed::FromRefSealed for T where T: FFI<Origin = FromRef> {}
 impl<T> RefFFI for T where T: FFI<Origin = FromRef> {}
 
+/// Side-table bookkeeping backing [`ForeignOwnable::try_from_foreign`]. Gated entirely
+/// behind the `ffi-validation` cargo feature (not declared in a manifest in this tree - see
+/// the crate's build setup) so that it costs nothing in release builds: with the feature
+/// off, `try_from_foreign` degrades to a direct call to `from_foreign` and none of this is
+/// even compiled.
+#[cfg(feature = "ffi-validation")]
+mod ffi_validation {
+    use std::any::TypeId;
+    use std::collections::HashMap;
+    use std::sync::{Mutex, OnceLock};
+
+    /// Bookkeeping for one stamped address: which type allocated it, and whether that
+    /// allocation has since been reclaimed (so a stale pointer can't be replayed).
+    struct Slot {
+        type_tag: TypeId,
+        generation: u64,
+        live: bool,
+    }
+
+    fn registry() -> &'static Mutex<HashMap<usize, Slot>> {
+        static REGISTRY: OnceLock<Mutex<HashMap<usize, Slot>>> = OnceLock::new();
+        REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+    }
+
+    /// Records that `addr` now holds a live allocation of type `T`, bumping the generation
+    /// counter if this address was previously stamped (e.g. reused by the allocator after
+    /// an earlier reclaim).
+    pub(super) fn stamp<T: 'static>(addr: usize) {
+        let mut registry = registry().lock().unwrap();
+        let generation = registry.get(&addr).map_or(0, |slot| slot.generation + 1);
+        registry.insert(
+            addr,
+            Slot {
+                type_tag: TypeId::of::<T>(),
+                generation,
+                live: true,
+            },
+        );
+    }
+
+    /// Checks that `addr` holds a live allocation stamped for type `T`, and marks it
+    /// reclaimed. Returns `false` (without reclaiming anything) on any tag mismatch, or if
+    /// the slot was already reclaimed.
+    pub(super) fn validate_and_reclaim<T: 'static>(addr: usize) -> bool {
+        let mut registry = registry().lock().unwrap();
+        match registry.get_mut(&addr) {
+            Some(slot) if slot.live && slot.type_tag == TypeId::of::<T>() => {
+                slot.live = false;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Unifies [`BoxFFI`] and [`ArcFFI`] behind a single pointer-manipulation vocabulary,
+/// so generic driver code can round-trip any owning FFI handle without knowing which
+/// of the two ownership families it belongs to.
+///
+/// [`RefFFI`] is deliberately not unified here: its pointers never own an allocation of
+/// their own (they borrow from an object owned elsewhere, e.g. a child object borrowing
+/// from its parent [`Session`](crate::session::BridgedSession)), so there is no value
+/// for `into_foreign`/`from_foreign` to take ownership *of* - unlike `Box`/`Arc`, `Ref`
+/// doesn't have an allocation step for this trait to wrap.
+pub trait ForeignOwnable: Sized {
+    /// The pointer ownership kind of this type's foreign pointer - [`Exclusive`] for
+    /// `Box`-backed types, [`Shared`] for `Arc`-backed types.
+    type PtrOwnership: Ownership;
+
+    /// The type yielded by [`ForeignOwnable::borrow`].
+    type Borrowed<'a>
+    where
+        Self: 'a;
+
+    /// The type yielded by [`ForeignOwnable::borrow_mut`].
+    type BorrowedMut<'a>
+    where
+        Self: 'a;
+
+    /// Consumes `self` and returns an owning pointer with `'static` lifetime.
+    /// The pointer must eventually be passed to [`ForeignOwnable::from_foreign`] (directly,
+    /// or via the type's own `BoxFFI::free`/`ArcFFI::free`) to avoid leaking it.
+    fn into_foreign(self) -> BridgedPtr<'static, Self, Self::PtrOwnership>;
+
+    /// Converts an owning pointer back into `Self`, reclaiming ownership.
+    ///
+    /// For [`Shared`]-ownership (`Arc`-backed) types, other clones of the handle may still
+    /// be alive, so this drops this reference and hands back a clone of the data (hence
+    /// the `Clone` bound on that impl) rather than assuming exclusive ownership.
+    ///
+    /// ## Safety
+    /// `ptr` must have been produced by a matching [`ForeignOwnable::into_foreign`]
+    /// call, and must not have already been consumed by an earlier `from_foreign` call.
+    unsafe fn from_foreign(ptr: BridgedPtr<'static, Self, Self::PtrOwnership>) -> Self;
+
+    /// Reborrows the pointee without transferring ownership.
+    ///
+    /// ## Safety
+    /// `ptr` must be valid - i.e. not dangling, and not yet consumed by
+    /// [`ForeignOwnable::from_foreign`].
+    #[allow(clippy::needless_lifetimes)]
+    unsafe fn borrow<'a>(ptr: BridgedPtr<'a, Self, Self::PtrOwnership>) -> Self::Borrowed<'a>;
+
+    /// Reborrows the pointee, mutably where the ownership kind allows it, without
+    /// transferring ownership.
+    ///
+    /// This always returns a reborrow of the *same* pointee - there is no way through
+    /// this method to make the foreign pointer refer to a different object. Callers
+    /// that want to replace the pointee must go through [`ForeignOwnable::from_foreign`]
+    /// followed by [`ForeignOwnable::into_foreign`] explicitly.
+    ///
+    /// ## Safety
+    /// `ptr` must be valid - i.e. not dangling, and not yet consumed by
+    /// [`ForeignOwnable::from_foreign`].
+    #[allow(clippy::needless_lifetimes)]
+    unsafe fn borrow_mut<'a>(ptr: BridgedPtr<'a, Self, Self::PtrOwnership>) -> Self::BorrowedMut<'a>;
+
+    /// Fallible counterpart to [`ForeignOwnable::from_foreign`].
+    ///
+    /// With the `ffi-validation` cargo feature enabled, every [`ForeignOwnable::into_foreign`]
+    /// call stamps its allocation's address with a per-type tag and generation counter in a
+    /// side table; this method checks that stamp before reconstructing `Self`, returning `None`
+    /// instead of dereferencing a pointer that doesn't match (wrong type) or was already
+    /// reclaimed (double free/use-after-free) - rather than the instant UB `from_foreign` risks
+    /// on a pointer that never really came from a matching `into_foreign` call.
+    ///
+    /// Without the feature (the default, in any build profile), the tag is
+    /// compiled out entirely and this behaves exactly like [`ForeignOwnable::from_foreign`].
+    ///
+    /// Only pointers round-tripped through `into_foreign`/`try_from_foreign` are tracked - a
+    /// handle freed through its own `BoxFFI::free`/`ArcFFI::free` instead is invisible to this
+    /// check.
+    ///
+    /// ## Safety
+    /// Same preconditions as [`ForeignOwnable::from_foreign`]; with `ffi-validation` enabled,
+    /// a pointer that doesn't satisfy them is rejected with `None` rather than being UB, but
+    /// without the feature this carries the exact same safety requirements as `from_foreign`.
+    unsafe fn try_from_foreign(ptr: BridgedPtr<'static, Self, Self::PtrOwnership>) -> Option<Self>
+    where
+        Self: 'static,
+    {
+        #[cfg(feature = "ffi-validation")]
+        {
+            let addr = ptr.to_raw()? as usize;
+            if !ffi_validation::validate_and_reclaim::<Self>(addr) {
+                return None;
+            }
+        }
+        Some(unsafe { Self::from_foreign(ptr) })
+    }
+}
+
+impl<T> ForeignOwnable for T
+where
+    T: FFI<Origin = FromBox> + 'static,
+{
+    type PtrOwnership = Exclusive;
+    type Borrowed<'a>
+        = &'a T
+    where
+        T: 'a;
+    type BorrowedMut<'a>
+        = &'a mut T
+    where
+        T: 'a;
+
+    fn into_foreign(self) -> BridgedPtr<'static, Self, Exclusive> {
+        let ptr = BoxFFI::into_ptr(Box::new(self));
+        #[cfg(feature = "ffi-validation")]
+        if let Some(raw) = ptr.to_raw() {
+            ffi_validation::stamp::<Self>(raw as usize);
+        }
+        ptr
+    }
+
+    unsafe fn from_foreign(ptr: BridgedPtr<'static, Self, Exclusive>) -> Self {
+        *BoxFFI::from_ptr(ptr).expect("ForeignOwnable::from_foreign called with a null pointer")
+    }
+
+    unsafe fn borrow<'a>(ptr: BridgedPtr<'a, Self, Exclusive>) -> &'a T {
+        BoxFFI::as_ref(ptr).expect("ForeignOwnable::borrow called with a null pointer")
+    }
+
+    unsafe fn borrow_mut<'a>(ptr: BridgedPtr<'a, Self, Exclusive>) -> &'a mut T {
+        BoxFFI::as_mut_ref(ptr).expect("ForeignOwnable::borrow_mut called with a null pointer")
+    }
+}
+
+impl<T> ForeignOwnable for T
+where
+    T: FFI<Origin = FromArc> + Clone + 'static,
+{
+    type PtrOwnership = Shared;
+    type Borrowed<'a>
+        = &'a T
+    where
+        T: 'a;
+    // Shared ownership grants no extra mutation via a borrow - `BorrowedMut` is the
+    // same immutable reference as `Borrowed`.
+    type BorrowedMut<'a>
+        = &'a T
+    where
+        T: 'a;
+
+    fn into_foreign(self) -> BridgedPtr<'static, Self, Shared> {
+        let ptr = ArcFFI::into_ptr(Arc::new(self));
+        #[cfg(feature = "ffi-validation")]
+        if let Some(raw) = ptr.to_raw() {
+            ffi_validation::stamp::<Self>(raw as usize);
+        }
+        ptr
+    }
+
+    unsafe fn from_foreign(ptr: BridgedPtr<'static, Self, Shared>) -> Self {
+        let arc = ArcFFI::from_ptr(ptr).expect("ForeignOwnable::from_foreign called with a null pointer");
+        // Other clones of this handle (e.g. made via `ArcFFI::cloned_from_ptr`) may still be
+        // alive, so unlike `BoxFFI`'s exclusively-owned pointers we can't assume we're
+        // reclaiming the sole reference. Clone the data out and drop our reference - mirroring
+        // `ArcFFI::free` - instead of panicking when the refcount is still greater than one.
+        let value = (*arc).clone();
+        drop(arc);
+        value
+    }
+
+    unsafe fn borrow<'a>(ptr: BridgedPtr<'a, Self, Shared>) -> &'a T {
+        ArcFFI::as_ref(ptr).expect("ForeignOwnable::borrow called with a null pointer")
+    }
+
+    unsafe fn borrow_mut<'a>(ptr: BridgedPtr<'a, Self, Shared>) -> &'a T {
+        ArcFFI::as_ref(ptr).expect("ForeignOwnable::borrow_mut called with a null pointer")
+    }
+}
+
+/// ```compile_fail,E0499
+/// # use csharp_wrapper::ffi::{BridgedOwnedExclusivePtr, BridgedBorrowedExclusivePtr, FFI, ForeignOwnable, FromBox};
+/// struct Foo;
+/// impl FFI for Foo {
+///     type Origin = FromBox;
+/// }
+///
+/// let mut ptr: BridgedOwnedExclusivePtr<Foo> = Foo.into_foreign();
+/// let borrowed_mut_ptr1: BridgedBorrowedExclusivePtr<Foo> = ptr.borrow_mut();
+/// let borrowed_mut_ptr2: BridgedBorrowedExclusivePtr<Foo> = ptr.borrow_mut();
+/// let mutref1 = unsafe { Foo::borrow_mut(borrowed_mut_ptr2) };
+/// let mutref2 = unsafe { Foo::borrow_mut(borrowed_mut_ptr1) };
+/// ```
+fn _test_foreign_ownable_cannot_have_two_mutable_reborrows() {}
+
 /// ```compile_fail,E0499
 /// # use csharp_wrapper::ffi::{BridgedOwnedExclusivePtr, BridgedBorrowedExclusivePtr, FFI, BoxFFI, FromBox};
 /// struct Foo;
@@ -576,3 +825,295 @@ fn _test_arc_ffi_cannot_clone_after_free() {}
 /// let immref = ArcFFI::cloned_from_ptr(borrowed_ptr);
 /// ```
 fn _test_arc_ffi_cannot_dereference_borrowed_after_drop() {}
+
+/// RAII wrapper around an owning [`BridgedPtr`] that frees the pointee on `Drop`
+/// unless [`ScopeGuard::dismiss`] is called first.
+///
+/// `BoxFFI::into_ptr`/`ArcFFI::into_ptr` hand back a raw `BridgedPtr`, which has no
+/// `Drop` of its own - a driver function that allocates one and then hits an early
+/// return on some later fallible step would leak it. Wrap the pointer in a
+/// `ScopeGuard` right after allocating, perform the fallible setup (using `Deref`/
+/// `DerefMut` to reach the pointee), and call `dismiss()` only on the success path,
+/// once the function has committed to handing ownership back to the C caller. Every
+/// error branch in between then frees the allocation automatically when the guard
+/// drops.
+pub struct ScopeGuard<T: Sized, P: Properties> {
+    ptr: Option<BridgedPtr<'static, T, P>>,
+    cleanup: fn(BridgedPtr<'static, T, P>),
+}
+
+impl<T: Sized + BoxFFI> ScopeGuard<T, Exclusive> {
+    /// Wraps a `Box`-owned pointer, freeing it via [`BoxFFI::free`] on `Drop` unless dismissed.
+    pub fn new_box(ptr: BridgedPtr<'static, T, Exclusive>) -> Self {
+        Self {
+            ptr: Some(ptr),
+            cleanup: BoxFFI::free,
+        }
+    }
+}
+
+impl<T: Sized + ArcFFI> ScopeGuard<T, Shared> {
+    /// Wraps an `Arc`-owned pointer, freeing it via [`ArcFFI::free`] on `Drop` unless dismissed.
+    pub fn new_arc(ptr: BridgedPtr<'static, T, Shared>) -> Self {
+        Self {
+            ptr: Some(ptr),
+            cleanup: ArcFFI::free,
+        }
+    }
+}
+
+impl<T: Sized, P: Properties> ScopeGuard<T, P> {
+    /// Consumes the guard and returns the inner pointer without running the cleanup -
+    /// for the success path that hands ownership back to the C caller.
+    pub fn dismiss(mut self) -> BridgedPtr<'static, T, P> {
+        self.ptr
+            .take()
+            .expect("ScopeGuard's pointer was already taken")
+    }
+
+    fn as_raw(&self) -> *mut T {
+        self.ptr
+            .as_ref()
+            .expect("ScopeGuard's pointer was already taken")
+            .to_raw()
+            .expect("ScopeGuard's pointer is null")
+    }
+}
+
+impl<T: Sized, P: Properties> Drop for ScopeGuard<T, P> {
+    fn drop(&mut self) {
+        if let Some(ptr) = self.ptr.take() {
+            (self.cleanup)(ptr);
+        }
+    }
+}
+
+impl<T: Sized, P: Properties> std::ops::Deref for ScopeGuard<T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: the pointer is valid for as long as the guard holds it onto - it was
+        // obtained from an owning allocation and hasn't been freed or dismissed yet.
+        unsafe { &*self.as_raw() }
+    }
+}
+
+impl<T: Sized> std::ops::DerefMut for ScopeGuard<T, Exclusive> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: same as `deref` above; `Exclusive` ownership additionally guarantees
+        // no other reference to the pointee can exist at the same time.
+        unsafe { &mut *self.as_raw() }
+    }
+}
+
+/// An `Arc<T>` guaranteed to have a strong count of 1, allowing mutable access via
+/// `DerefMut` while the object is still being built up - before it becomes visible
+/// through any shared pointer.
+///
+/// `ArcFFI` only lets you share data that is already `Arc`-allocated; there was
+/// previously no supported way to mutate an object after allocation but before it's
+/// published. `UniqueArc` lets driver code construct a complex shared object (e.g. a
+/// cluster/session config) field-by-field mutably, then [`UniqueArc::share`] it into a
+/// normal refcounted `Arc` ready for [`ArcFFI::into_ptr`] - without `UnsafeCell` tricks
+/// or an intermediate `Box`.
+pub struct UniqueArc<T> {
+    inner: Arc<T>,
+}
+
+impl<T> UniqueArc<T>
+where
+    T: FFI<Origin = FromArc>,
+{
+    /// Allocates a new `UniqueArc` with a strong count of 1.
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Arc::new(value),
+        }
+    }
+
+    /// Freezes the object into a normal refcounted `Arc`, giving up mutable access.
+    pub fn share(self) -> Arc<T> {
+        self.inner
+    }
+}
+
+impl<T> std::ops::Deref for UniqueArc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T> std::ops::DerefMut for UniqueArc<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: `new` is the only constructor and always starts at strong count 1;
+        // `share` consumes `self`, so no `Arc` clone can exist while a `UniqueArc`
+        // still does. This is therefore the sole reference to the data.
+        Arc::get_mut(&mut self.inner).expect("UniqueArc's Arc must have a strong count of 1")
+    }
+}
+
+/// ```compile_fail,E0382
+/// # use csharp_wrapper::ffi::{FFI, FromArc, UniqueArc};
+/// struct Foo(i32);
+/// impl FFI for Foo {
+///     type Origin = FromArc;
+/// }
+///
+/// let mut unique = UniqueArc::new(Foo(0));
+/// let arc = unique.share();
+/// let mutref = &mut *unique;
+/// ```
+fn _test_unique_arc_cannot_deref_mut_after_share() {}
+
+/// Generates an FFI handle newtype together with its `impl FFI` and the thin
+/// `extern "C"` free function (and, for `FromArc` origins, clone function) that every
+/// exported handle otherwise has to hand-write separately.
+///
+/// ```ignore
+/// bridged_type! {
+///     pub struct BridgedFoo;
+///     type CType = Foo;
+///     type Origin = FromArc;
+///     fn free = foo_free;
+///     fn clone = foo_clone;
+///     fn drop = |_inner: &Foo| tracing::debug!("foo freed");
+/// }
+/// ```
+///
+/// `fn drop` runs as a side-effecting hook just before the handle's last reference is
+/// actually dropped (e.g. for logging); it does not replace `CType`'s own `Drop` impl,
+/// which still runs normally once the `Box`/`Arc` goes out of scope. `fn clone` is only
+/// accepted for `FromArc` origins, where it generates an `Arc::increment_strong_count`-based
+/// clone via [`ArcFFI::cloned_from_ptr`] - `FromBox` handles have no refcount to share, so
+/// there is no equivalent clause for them.
+#[macro_export]
+macro_rules! bridged_type {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident;
+        type CType = $ctype:ty;
+        type Origin = FromBox;
+        fn free = $free_fn:ident;
+        fn drop = $drop:expr;
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            pub(crate) inner: $ctype,
+        }
+
+        impl $crate::ffi::FFI for $name {
+            type Origin = $crate::ffi::FromBox;
+        }
+
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $free_fn(ptr: $crate::ffi::BridgedOwnedExclusivePtr<$name>) {
+            if let Some(boxed) = $crate::ffi::BoxFFI::from_ptr(ptr) {
+                let drop_hook: fn(&$ctype) = $drop;
+                drop_hook(&boxed.inner);
+            }
+        }
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident;
+        type CType = $ctype:ty;
+        type Origin = FromArc;
+        fn free = $free_fn:ident;
+        $(fn clone = $clone_fn:ident;)?
+        fn drop = $drop:expr;
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            pub(crate) inner: $ctype,
+        }
+
+        impl $crate::ffi::FFI for $name {
+            type Origin = $crate::ffi::FromArc;
+        }
+
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $free_fn(ptr: $crate::ffi::BridgedOwnedSharedPtr<$name>) {
+            if let Some(arc) = $crate::ffi::ArcFFI::from_ptr(ptr) {
+                // Releasing a reference while others are still alive skips the hook.
+                if let Some(last) = ::std::sync::Arc::into_inner(arc) {
+                    let drop_hook: fn(&$ctype) = $drop;
+                    drop_hook(&last.inner);
+                }
+            }
+        }
+
+        $(
+            #[unsafe(no_mangle)]
+            pub extern "C" fn $clone_fn(
+                ptr: $crate::ffi::BridgedBorrowedSharedPtr<'_, $name>,
+            ) -> $crate::ffi::BridgedOwnedSharedPtr<$name> {
+                match $crate::ffi::ArcFFI::cloned_from_ptr(ptr) {
+                    Some(arc) => $crate::ffi::ArcFFI::into_ptr(arc),
+                    None => $crate::ffi::BridgedPtr::null(),
+                }
+            }
+        )?
+    };
+}
+
+/// Bundles an owning handle (a `Box<X>` or `Arc<X>`) together with a [`BridgedPtr`] that
+/// points *into* it - e.g. a `&RowView` living inside a `Box<ResultPage>` - so driver code
+/// can hand C a pointer to a sub-object without re-boxing the projected value separately.
+/// Rust's borrow rules make it impossible to return the owner and a reference into it as
+/// two independent values; bundling them into one struct sidesteps that.
+///
+/// The projected pointer is only ever handed out borrowed (via [`BridgedOwningPtr::as_ref`]/
+/// [`BridgedOwningPtr::borrow`]), tied to `&self` - so it cannot outlive `owner`. Dropping
+/// `owner` (which [`BridgedOwningPtr::free`] does) is therefore the only way to invalidate
+/// it, and the borrow checker enforces that no such reference survives the drop.
+pub struct BridgedOwningPtr<O, T: Sized> {
+    owner: O,
+    projected: BridgedPtr<'static, T, Shared>,
+}
+
+impl<O: Deref, T: Sized> BridgedOwningPtr<O, T> {
+    /// Projects `project` from `owner`'s target into an interior pointer, bundling both
+    /// together. `owner` (a `Box<X>`/`Arc<X>`) is a thin handle to a stable heap
+    /// allocation, so moving `owner` around afterwards (e.g. as part of moving `self`)
+    /// does not move the data `project` points into - the projected pointer stays valid
+    /// for as long as `owner` is kept alive inside `self`.
+    pub fn map(owner: O, project: impl FnOnce(&O::Target) -> &T) -> Self {
+        let projected = project(&owner) as *const T;
+        // SAFETY: `projected` points into `owner`'s heap allocation, which this struct
+        // keeps alive for exactly as long as `self` exists.
+        let projected = unsafe { BridgedPtr::from_raw(projected) };
+        Self { owner, projected }
+    }
+
+    /// Hands out the projected reference, tied to `&self` (and therefore to `owner`
+    /// staying alive).
+    pub fn as_ref(&self) -> &T {
+        self.projected
+            .borrow()
+            .into_ref()
+            .expect("BridgedOwningPtr's projected pointer is never null")
+    }
+
+    /// Reborrows the projected pointer without transferring ownership of `owner`.
+    pub fn borrow(&self) -> BridgedPtr<'_, T, Shared> {
+        self.projected.borrow()
+    }
+
+    /// Drops `owner`, invalidating the projected pointer.
+    pub fn free(self) {
+        drop(self);
+    }
+}
+
+/// ```compile_fail,E0505
+/// # use csharp_wrapper::ffi::BridgedOwningPtr;
+/// let owning: BridgedOwningPtr<Box<(i32, i32)>, i32> =
+///     BridgedOwningPtr::map(Box::new((1, 2)), |pair| &pair.0);
+/// let interior = owning.as_ref();
+/// owning.free();
+/// let _ = *interior;
+/// ```
+fn _test_bridged_owning_ptr_cannot_use_interior_ref_after_free() {}
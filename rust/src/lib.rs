@@ -1,9 +1,13 @@
+mod error;
 pub mod ffi;
+mod logging;
 mod prepared_statement;
+mod pre_serialized_values;
 mod row_set;
 pub mod serialize;
 mod session;
 mod task;
+mod typed_values;
 
 use std::ffi::{CStr, c_char};
 use std::marker::PhantomData;
@@ -0,0 +1,120 @@
+//! Application-controlled logging.
+//!
+//! The FFI functions in this crate used to write diagnostics straight to stdout via
+//! `println!`, which is unusable in a hosted .NET process and cannot be filtered or
+//! routed anywhere useful. This module installs a `tracing` subscriber that forwards
+//! both this crate's own events and the underlying `scylla` crate's `tracing` events
+//! to a callback supplied by the C# side, so embedders can bridge Rust diagnostics
+//! into their existing logging framework.
+
+use std::ffi::{CString, c_char};
+use std::fmt::Write as _;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::prelude::*;
+
+/// Severity levels understood by the managed logging callback. Lower values are more
+/// severe. Numeric values are part of the FFI contract - do not renumber.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Debug = 3,
+    Trace = 4,
+}
+
+fn tracing_level_to_code(level: &Level) -> i32 {
+    match *level {
+        Level::ERROR => LogLevel::Error as i32,
+        Level::WARN => LogLevel::Warn as i32,
+        Level::INFO => LogLevel::Info as i32,
+        Level::DEBUG => LogLevel::Debug as i32,
+        Level::TRACE => LogLevel::Trace as i32,
+    }
+}
+
+/// Function pointer type for the C#-supplied logging callback.
+/// `target` and `msg` are NUL-terminated UTF-8 strings valid only for the duration of the call.
+type LoggingCallback = unsafe extern "C" fn(level: i32, target: *const c_char, msg: *const c_char);
+
+static CALLBACK: OnceLock<LoggingCallback> = OnceLock::new();
+static LEVEL_THRESHOLD: AtomicI32 = AtomicI32::new(LogLevel::Info as i32);
+
+/// Installs `callback` as the sink for all `tracing` events emitted by this crate and
+/// by the underlying `scylla` driver, filtered to `level` and more severe.
+///
+/// Calling this more than once only updates the level threshold - the callback passed
+/// to the first call stays in place and later callbacks are ignored, and the
+/// underlying `tracing` subscriber is installed at most once per process.
+#[unsafe(no_mangle)]
+pub extern "C" fn logging_set_callback(level: i32, callback: LoggingCallback) {
+    LEVEL_THRESHOLD.store(level, Ordering::Relaxed);
+    // `OnceLock::set` only succeeds the first time; subsequent calls just update the
+    // level threshold above, since swapping the callback itself would need a Mutex
+    // and the typical use case sets it once at startup.
+    let _ = CALLBACK.set(callback);
+
+    static SUBSCRIBER_INIT: std::sync::Once = std::sync::Once::new();
+    SUBSCRIBER_INIT.call_once(|| {
+        let subscriber = tracing_subscriber::registry().with(CallbackLayer);
+        // Ignore the error: if some other part of the host process already installed
+        // a global subscriber, we simply don't get to forward events - there's nothing
+        // else useful to do from an FFI entry point.
+        let _ = tracing::subscriber::set_global_default(subscriber);
+    });
+}
+
+/// A `tracing_subscriber` layer that forwards every event to the registered callback.
+struct CallbackLayer;
+
+impl<S: Subscriber> Layer<S> for CallbackLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let Some(callback) = CALLBACK.get() else {
+            return;
+        };
+
+        let level = tracing_level_to_code(event.metadata().level());
+        if level > LEVEL_THRESHOLD.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let Ok(target) = CString::new(event.metadata().target()) else {
+            return;
+        };
+        let Ok(msg) = CString::new(visitor.message) else {
+            return;
+        };
+
+        unsafe { callback(level, target.as_ptr(), msg.as_ptr()) };
+    }
+}
+
+/// Collects an event's fields into a single human-readable message, mirroring the
+/// structured fields (statement text, node address, value counts, ...) that used to
+/// be interpolated directly into `println!` calls.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            if !self.message.is_empty() {
+                self.message.push(' ');
+            }
+            let _ = write!(self.message, "{}={:?}", field.name(), value);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 #[path = "pre_serialized_values.rs"]
 pub(crate) mod pre_serialized_values;
+#[path = "pre_serialized_values_conversion.rs"]
+pub(crate) mod pre_serialized_values_conversion;
 #[path = "pre_serialized_values_safe.rs"]
 pub(crate) mod pre_serialized_values_safe;
 #[path = "pre_serialized_values_unsafe.rs"]
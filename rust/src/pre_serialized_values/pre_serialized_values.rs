@@ -78,9 +78,15 @@ pub struct PreSerializedValues {
 }
 
 impl PreSerializedValues {
+    /// Backed by [`SafePreSerializedValues`], which copies every value into an owned
+    /// `Vec<u8>` cell instead of retaining the caller's pointer - trivially `Send`/`Sync`
+    /// with no pinning contract, at the cost of one memcpy per value.
     fn new_safe() -> Self { Self { inner: Box::new(SafePreSerializedValues::new()) } }
+    /// Backed by [`UnsafePreSerializedValues`], which retains a raw pointer into the
+    /// caller's buffer instead of copying it. Faster, but the caller must keep every
+    /// buffer pinned and immutable until serialization completes.
     fn new_unsafe() -> Self { Self { inner: Box::new(UnsafePreSerializedValues::new()) } }
-    unsafe fn add_value(&mut self, ptr: *const u8, len: usize) { unsafe { self.inner.add_value(ptr, len) } }
+    pub(crate) unsafe fn add_value(&mut self, ptr: *const u8, len: usize) { unsafe { self.inner.add_value(ptr, len) } }
     fn add_null(&mut self) { self.inner.add_null(); }
     fn add_unset(&mut self) { self.inner.add_unset(); }
     pub fn len(&self) -> usize { self.inner.len() }
@@ -96,11 +102,19 @@ impl SerializeRow for PreSerializedValues {
 }
 
 // ===== FFI exported functions =====
+
+/// Creates a copying, lifetime-safe values builder: `add_value` copies the bytes it is
+/// given, so the caller's buffer can be freed or reused as soon as the call returns.
+/// Prefer this constructor unless the extra memcpy per value has been measured to matter,
+/// in which case see `pre_serialized_values_unsafe_new`.
 #[unsafe(no_mangle)]
 pub extern "C" fn pre_serialized_values_new() -> BridgedOwnedExclusivePtr<PreSerializedValues> {
     BoxFFI::into_ptr(Box::new(PreSerializedValues::new_safe()))
 }
 
+/// Creates a values builder that retains a raw pointer into the caller's buffer instead
+/// of copying it. Faster, but the caller must keep every buffer passed to `add_value`
+/// pinned and immutable until serialization completes.
 #[unsafe(no_mangle)]
 pub extern "C" fn pre_serialized_values_unsafe_new() -> BridgedOwnedExclusivePtr<PreSerializedValues> {
     BoxFFI::into_ptr(Box::new(PreSerializedValues::new_unsafe()))
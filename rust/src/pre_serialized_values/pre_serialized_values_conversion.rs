@@ -0,0 +1,218 @@
+//! Textual/primitive value conversions for `pre_serialized_values_add_converted`.
+//!
+//! [`PreSerializedValues::add_value`](crate::pre_serialized_values::pre_serialized_values::PreSerializedValues)
+//! demands already-CQL-encoded bytes. This module lets callers instead push a raw
+//! textual/primitive input plus a named [`Conversion`] and have it encoded to the
+//! correct CQL wire format on the Rust side - useful for callers that would rather not
+//! hand-encode CQL wire values themselves.
+
+use std::str::FromStr;
+
+use crate::error::{BridgedError, set_out_error};
+use crate::ffi::{BoxFFI, BridgedBorrowedExclusivePtr};
+use crate::pre_serialized_values::PreSerializedValues;
+
+/// A named conversion applied to a raw textual/primitive input before it is appended
+/// as a cell to a [`PreSerializedValues`].
+#[derive(Debug, Clone)]
+pub(crate) enum Conversion {
+    Bytes,
+    /// CQL `int` - 4-byte big-endian `i32`.
+    Integer,
+    /// CQL `bigint` - 8-byte big-endian `i64`.
+    BigInt,
+    /// CQL `float` - 4-byte big-endian `f32`.
+    Float,
+    /// CQL `double` - 8-byte big-endian `f64`.
+    Double,
+    Boolean,
+    Timestamp,
+    TimestampFmt(String),
+    TimestampTZFmt(String),
+}
+
+impl FromStr for Conversion {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        if let Some(fmt) = name.strip_prefix("timestamp_tz_fmt:") {
+            return Ok(Conversion::TimestampTZFmt(fmt.to_owned()));
+        }
+        if let Some(fmt) = name.strip_prefix("timestamp_fmt:") {
+            return Ok(Conversion::TimestampFmt(fmt.to_owned()));
+        }
+
+        match name {
+            "int" | "integer" => Ok(Conversion::Integer),
+            "bigint" => Ok(Conversion::BigInt),
+            "float" => Ok(Conversion::Float),
+            "double" => Ok(Conversion::Double),
+            "bool" | "boolean" => Ok(Conversion::Boolean),
+            "bytes" | "string" | "asis" => Ok(Conversion::Bytes),
+            "timestamp" => Ok(Conversion::Timestamp),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Conversion {
+    /// Applies this conversion to `input`, producing the CQL wire-format bytes for the cell.
+    fn apply(&self, input: &[u8]) -> Result<Vec<u8>, BridgedError> {
+        match self {
+            Conversion::Bytes => Ok(input.to_vec()),
+            Conversion::Integer => {
+                let text = as_utf8(input, "integer")?;
+                let value: i32 = text.trim().parse().map_err(|_| {
+                    BridgedError::conversion_failed(format!("'{text}' is not a valid integer"))
+                })?;
+                Ok(value.to_be_bytes().to_vec())
+            }
+            Conversion::BigInt => {
+                let text = as_utf8(input, "bigint")?;
+                let value: i64 = text.trim().parse().map_err(|_| {
+                    BridgedError::conversion_failed(format!("'{text}' is not a valid bigint"))
+                })?;
+                Ok(value.to_be_bytes().to_vec())
+            }
+            Conversion::Float => {
+                let text = as_utf8(input, "float")?;
+                let value: f32 = text.trim().parse().map_err(|_| {
+                    BridgedError::conversion_failed(format!("'{text}' is not a valid float"))
+                })?;
+                Ok(value.to_be_bytes().to_vec())
+            }
+            Conversion::Double => {
+                let text = as_utf8(input, "double")?;
+                let value: f64 = text.trim().parse().map_err(|_| {
+                    BridgedError::conversion_failed(format!("'{text}' is not a valid double"))
+                })?;
+                Ok(value.to_be_bytes().to_vec())
+            }
+            Conversion::Boolean => {
+                let text = as_utf8(input, "boolean")?;
+                match text.trim().to_ascii_lowercase().as_str() {
+                    "true" | "1" => Ok(vec![1]),
+                    "false" | "0" => Ok(vec![0]),
+                    other => Err(BridgedError::conversion_failed(format!(
+                        "'{other}' is not a valid boolean"
+                    ))),
+                }
+            }
+            Conversion::Timestamp => {
+                let text = as_utf8(input, "timestamp")?;
+                Ok(parse_timestamp(text)?.to_be_bytes().to_vec())
+            }
+            Conversion::TimestampFmt(fmt) => {
+                let text = as_utf8(input, "timestamp")?;
+                let naive = chrono::NaiveDateTime::parse_from_str(text, fmt).map_err(|err| {
+                    BridgedError::conversion_failed(format!(
+                        "'{text}' does not match format '{fmt}': {err}"
+                    ))
+                })?;
+                // Unlike `TimestampTZFmt`, this format carries no UTC offset, so the naive
+                // datetime is interpreted in the local timezone.
+                let millis = local_timestamp_millis(&naive, text)?;
+                Ok(millis.to_be_bytes().to_vec())
+            }
+            Conversion::TimestampTZFmt(fmt) => {
+                let text = as_utf8(input, "timestamp")?;
+                let millis = chrono::DateTime::parse_from_str(text, fmt)
+                    .map(|dt| dt.timestamp_millis())
+                    .map_err(|err| {
+                        BridgedError::conversion_failed(format!(
+                            "'{text}' does not match format '{fmt}': {err}"
+                        ))
+                    })?;
+                Ok(millis.to_be_bytes().to_vec())
+            }
+        }
+    }
+}
+
+fn as_utf8<'a>(input: &'a [u8], kind: &str) -> Result<&'a str, BridgedError> {
+    std::str::from_utf8(input)
+        .map_err(|_| BridgedError::conversion_failed(format!("{kind} input is not valid UTF-8")))
+}
+
+/// Resolves a naive (timezone-less) datetime to epoch milliseconds in the local timezone.
+///
+/// Fails if `naive` falls in a DST fold/gap, where the local timezone maps it to zero or
+/// two instants instead of exactly one.
+fn local_timestamp_millis(naive: &chrono::NaiveDateTime, text: &str) -> Result<i64, BridgedError> {
+    use chrono::TimeZone;
+
+    chrono::Local
+        .from_local_datetime(naive)
+        .single()
+        .map(|dt| dt.timestamp_millis())
+        .ok_or_else(|| {
+            BridgedError::conversion_failed(format!(
+                "'{text}' is ambiguous or does not exist in the local timezone"
+            ))
+        })
+}
+
+/// Parses either an RFC3339 timestamp or a raw unix-epoch-milliseconds integer.
+fn parse_timestamp(text: &str) -> Result<i64, BridgedError> {
+    if let Ok(millis) = text.trim().parse::<i64>() {
+        return Ok(millis);
+    }
+    chrono::DateTime::parse_from_rfc3339(text)
+        .map(|dt| dt.timestamp_millis())
+        .map_err(|err| {
+            BridgedError::conversion_failed(format!(
+                "'{text}' is not a valid RFC3339 timestamp or unix-epoch-milliseconds integer: {err}"
+            ))
+        })
+}
+
+/// Applies `conversion_name` to `input` and appends the resulting cell to `values_ptr`.
+///
+/// Surfaces an unknown conversion name or a conversion failure through `out_error`
+/// instead of panicking. Returns 1 on success, 0 otherwise.
+#[unsafe(no_mangle)]
+pub extern "C" fn pre_serialized_values_add_converted(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    conversion_name_ptr: *const u8,
+    conversion_name_len: usize,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_error: *mut *mut BridgedError,
+) -> i32 {
+    let Some(values) = BoxFFI::as_mut_ref(values_ptr) else {
+        return 0;
+    };
+
+    let name_bytes = unsafe { std::slice::from_raw_parts(conversion_name_ptr, conversion_name_len) };
+    let name = match std::str::from_utf8(name_bytes) {
+        Ok(name) => name,
+        Err(_) => {
+            set_out_error(
+                out_error,
+                BridgedError::conversion_failed("conversion name is not valid UTF-8"),
+            );
+            return 0;
+        }
+    };
+
+    let conversion = match Conversion::from_str(name) {
+        Ok(conversion) => conversion,
+        Err(()) => {
+            set_out_error(out_error, BridgedError::unknown_conversion(name));
+            return 0;
+        }
+    };
+
+    let input = unsafe { std::slice::from_raw_parts(input_ptr, input_len) };
+
+    match conversion.apply(input) {
+        Ok(bytes) => {
+            unsafe { values.add_value(bytes.as_ptr(), bytes.len()) };
+            1
+        }
+        Err(err) => {
+            set_out_error(out_error, err);
+            0
+        }
+    }
+}
@@ -3,6 +3,13 @@ use scylla::serialize::writers::RowWriter;
 use scylla::serialize::SerializationError;
 use crate::pre_serialized_values::{PreSerializedValuesTrait, HasCells};
 
+/// The copying, lifetime-safe counterpart to [`UnsafePreSerializedValues`](crate::pre_serialized_values::pre_serialized_values_unsafe::UnsafePreSerializedValues).
+///
+/// `add_value` copies the incoming bytes into an owned `Vec<u8>` cell, so this type is
+/// trivially `Send`/`Sync` and carries no pinning contract: the caller's buffer can be
+/// freed or reused the moment `add_value` returns. The cost is one memcpy per value -
+/// callers with short-lived or reused buffers should prefer this over
+/// `UnsafePreSerializedValues`, trading that copy for the removed pinning requirement.
 #[derive(Debug)]
 pub struct SafePreSerializedValues {
     cells: Vec<SafeCell>,
@@ -32,7 +39,10 @@ impl SerializeRow for SafePreSerializedValues {
             ctx,
             writer,
             |cw, cell| match cell {
-                SafeCell::Bytes(b) => cw.set_value(b).map(|_proof| ()).map_err(SerializationError::new),
+                SafeCell::Bytes(b) => cw.set_value(b).map(|_proof| ()).map_err(|err| {
+                    crate::serialize::set_last_error(&err);
+                    SerializationError::new(err)
+                }),
                 SafeCell::Null => { let _ = cw.set_null(); Ok(()) },
                 SafeCell::Unset => { let _ = cw.set_unset(); Ok(()) },
             },
@@ -1,6 +1,8 @@
 use scylla::statement::prepared::PreparedStatement;
 
-use crate::ffi::{ArcFFI, BridgedBorrowedSharedPtr, FFI, FromArc};
+use crate::ffi::{ArcFFI, BoxFFI, BridgedBorrowedSharedPtr, BridgedOwnedExclusivePtr, BridgedPtr, FFI, FromArc};
+use crate::row_set::column_type_to_code;
+use scylla::frame::response::result::ColumnType;
 
 #[derive(Debug)]
 pub struct BridgedPreparedStatement {
@@ -20,3 +22,59 @@ pub extern "C" fn prepared_statement_is_lwt(
         .inner
         .is_confirmed_lwt() as _
 }
+
+/// Returns the number of bind markers (`?`) in the prepared statement.
+#[unsafe(no_mangle)]
+pub extern "C" fn prepared_statement_column_count(
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+) -> usize {
+    match ArcFFI::as_ref(prepared_statement_ptr) {
+        Some(ps) => ps.inner.get_variable_col_specs().iter().count(),
+        None => 0,
+    }
+}
+
+type SetBindMarkerMetadata = unsafe extern "C" fn(
+    value_index: usize,
+    name_ptr: *const u8,
+    name_len: usize,
+    type_code: usize,
+    type_info_handle: BridgedOwnedExclusivePtr<ColumnType<'static>>,
+);
+
+/// Calls back into C# for each bind marker to provide its name and CQL type,
+/// so the managed layer can validate and serialize arguments against the real
+/// schema instead of guessing. `set_metadata` is called synchronously for each marker.
+#[unsafe(no_mangle)]
+pub extern "C" fn prepared_statement_fill_bind_markers_metadata(
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    set_metadata: SetBindMarkerMetadata,
+) -> i32 {
+    let Some(ps) = ArcFFI::as_ref(prepared_statement_ptr) else {
+        return 0;
+    };
+
+    for (i, spec) in ps.inner.get_variable_col_specs().iter().enumerate() {
+        let name = spec.name();
+        let name_ptr = if name.is_empty() {
+            std::ptr::null()
+        } else {
+            name.as_ptr()
+        };
+        let name_len = name.len();
+
+        let type_code = column_type_to_code(spec.typ()) as usize;
+
+        let mut type_info_handle: BridgedOwnedExclusivePtr<ColumnType> = BridgedPtr::null();
+        if type_code >= 0x0020 {
+            let boxed = Box::new(spec.typ().clone());
+            type_info_handle = BoxFFI::into_ptr(boxed);
+        }
+
+        unsafe {
+            set_metadata(i, name_ptr, name_len, type_code, type_info_handle);
+        }
+    }
+
+    1
+}
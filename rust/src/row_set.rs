@@ -1,9 +1,9 @@
 use scylla::client::pager::QueryPager;
 use scylla::cluster::metadata::CollectionType;
-use scylla::errors::DeserializationError;
 use scylla::frame::response::result::{ColumnType, NativeType};
 
 use crate::FfiPtr;
+use crate::error::{BridgedError, set_out_error};
 use crate::ffi::{
     ArcFFI, BoxFFI, BridgedBorrowedSharedPtr, BridgedOwnedExclusivePtr, BridgedOwnedSharedPtr,
     BridgedPtr, FFI, FromArc, FromBox,
@@ -37,7 +37,60 @@ impl FFI for ColumnType<'_> {
 #[unsafe(no_mangle)]
 pub extern "C" fn row_set_free(row_set_ptr: BridgedOwnedSharedPtr<RowSet>) {
     ArcFFI::free(row_set_ptr);
-    println!("RowSet freed");
+    tracing::debug!("row set freed");
+}
+
+/// Entry point for exporting a `RowSet`'s paging state as an opaque byte buffer.
+/// Exporting is not implemented: this never produces a buffer. It always sets
+/// `*out_bytes_ptr` to null and `*out_len`/`*out_has_more` to 0, writes an
+/// `ErrorCategory::Unsupported` error through `out_error`, and returns 0.
+///
+/// `QueryPager` (used here for transparent multi-page streaming via
+/// `next_column_iterator`) does not expose its accumulated paging state through a
+/// stable accessor in the pinned driver version. Capturing it would mean switching
+/// this code path from `QueryPager` to the driver's manual single-page API
+/// (`Session::query_single_page` + `PagingState`/`PagingStateResponse`). The function
+/// exists so callers get a defined, explicit failure instead of a missing symbol.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_get_paging_state(
+    row_set_ptr: BridgedBorrowedSharedPtr<'_, RowSet>,
+    out_bytes_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    out_has_more: *mut i32,
+    out_error: *mut *mut BridgedError,
+) -> i32 {
+    let _ = row_set_ptr;
+    // Leave the out-params in a defined state even though we're about to fail, so a
+    // caller that forgets to check the return code doesn't read uninitialized memory.
+    unsafe {
+        if !out_bytes_ptr.is_null() {
+            *out_bytes_ptr = std::ptr::null_mut();
+        }
+        if !out_len.is_null() {
+            *out_len = 0;
+        }
+        if !out_has_more.is_null() {
+            *out_has_more = 0;
+        }
+    }
+    set_out_error(
+        out_error,
+        BridgedError::unsupported(
+            "paging state checkpointing is deferred: not supported for the QueryPager-based streaming path",
+        ),
+    );
+    0
+}
+
+/// Frees a byte buffer previously returned by [`row_set_get_paging_state`].
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_free_paging_state(bytes_ptr: *mut u8, len: usize) {
+    if bytes_ptr.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Vec::from_raw_parts(bytes_ptr, len, len));
+    }
 }
 
 #[unsafe(no_mangle)]
@@ -69,9 +122,18 @@ pub extern "C" fn row_set_fill_columns_metadata(
     row_set_ptr: BridgedBorrowedSharedPtr<'_, RowSet>,
     columns_ptr: ColumnsPtr,
     set_metadata: SetMetadata,
+    out_error: *mut *mut BridgedError,
 ) -> i32 {
-    let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
-    let pager = row_set.pager.lock().unwrap();
+    let Some(row_set) = ArcFFI::as_ref(row_set_ptr) else {
+        return 0;
+    };
+    let pager = match row_set.pager.lock() {
+        Ok(pager) => pager,
+        Err(_) => {
+            set_out_error(out_error, BridgedError::lock_poisoned());
+            return 0;
+        }
+    };
 
     // Iterate column specs and call the metadata setter
     for (i, spec) in pager.column_specs().iter().enumerate() {
@@ -167,26 +229,144 @@ pub extern "C" fn row_set_next_row<'row_set>(
     columns_ptr: ColumnsPtr,
     values_ptr: ValuesPtr,
     serializer_ptr: SerializerPtr,
+    out_error: *mut *mut BridgedError,
 ) -> i32 {
-    let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
-    let mut pager = row_set.pager.lock().unwrap();
+    let Some(row_set) = ArcFFI::as_ref(row_set_ptr) else {
+        return 0;
+    };
+    let mut pager = match row_set.pager.lock() {
+        Ok(pager) => pager,
+        Err(_) => {
+            set_out_error(out_error, BridgedError::lock_poisoned());
+            return 0;
+        }
+    };
     let num_columns = pager.column_specs().len();
 
     let deserialize_fut = async {
-        if let Some(Ok(mut column_iterator)) = pager.next_column_iterator().await {
-            // For each column in the row, we call `deserialize_value()`.
+        match pager.next_column_iterator().await {
+            Some(Ok(mut column_iterator)) => {
+                // For each column in the row, we call `deserialize_value()`.
+                for value_index in 0..num_columns {
+                    let raw_column = match column_iterator.next() {
+                        Some(Ok(raw_column)) => raw_column,
+                        Some(Err(err)) => return Err(BridgedError::deserialization_failed(err)),
+                        None => return Err(BridgedError::too_few_columns()),
+                    };
+
+                    if let Some(frame_slice) = raw_column.slice {
+                        unsafe {
+                            deserialize_value(
+                                columns_ptr,
+                                values_ptr,
+                                value_index,
+                                serializer_ptr,
+                                frame_slice.as_slice().as_ptr(),
+                                frame_slice.as_slice().len(),
+                            );
+                        }
+                    } else {
+                        // The value is null, so we skip deserialization.
+                        // We can do that because `object[] values` in C# is initialized with nulls.
+                        continue;
+                    }
+                }
+                Ok(true)
+            }
+            Some(Err(err)) => Err(BridgedError::deserialization_failed(err)),
+            None => {
+                tracing::debug!("no more rows available");
+                Ok(false)
+            }
+        }
+    };
+
+    match BridgedFuture::block_on(deserialize_fut) {
+        Ok(has_row) => has_row as i32,
+        Err(err) => {
+            set_out_error(out_error, err);
+            0
+        }
+    }
+}
+
+type DeserializeValueIndexed = unsafe extern "C" fn(
+    columns_ptr: ColumnsPtr,
+    values_ptr: ValuesPtr,
+    row_index: usize,
+    value_index: usize,
+    serializer_ptr: SerializerPtr,
+    frame_slice_ptr: *const u8,
+    length: usize,
+);
+
+/// Delivers up to `max_rows` rows from the pager in a single `block_on`, invoking
+/// `deserialize_value` for every cell of every row. It is not limited to the rows
+/// already buffered: when the current page runs out, the pager fetches the next page
+/// from the server within the same call.
+///
+/// `row_set_next_row` pays the cost of a `block_on` and a mutex lock once per row,
+/// which dominates for large result sets. This entry point amortizes that cost across
+/// a whole batch for throughput-sensitive callers, at the cost of `values_ptr` needing
+/// to be sized to hold `max_rows` rows instead of one.
+///
+/// Returns the number of rows actually delivered (which may be less than `max_rows` if
+/// the result set is exhausted first), and sets `*out_more_rows` to `1` if further rows
+/// may still be available, `0` if the result set is known to be exhausted. On failure,
+/// writes a [`BridgedError`] through `out_error` and returns the rows delivered before
+/// the failure occurred.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_next_page(
+    row_set_ptr: BridgedBorrowedSharedPtr<'_, RowSet>,
+    deserialize_value: DeserializeValueIndexed,
+    columns_ptr: ColumnsPtr,
+    values_ptr: ValuesPtr,
+    serializer_ptr: SerializerPtr,
+    max_rows: usize,
+    out_more_rows: *mut i32,
+    out_error: *mut *mut BridgedError,
+) -> usize {
+    let Some(row_set) = ArcFFI::as_ref(row_set_ptr) else {
+        return 0;
+    };
+    let mut pager = match row_set.pager.lock() {
+        Ok(pager) => pager,
+        Err(_) => {
+            set_out_error(out_error, BridgedError::lock_poisoned());
+            return 0;
+        }
+    };
+    let num_columns = pager.column_specs().len();
+
+    let drain_fut = async {
+        let mut rows_delivered = 0usize;
+        let mut exhausted = false;
+
+        while rows_delivered < max_rows {
+            let mut column_iterator = match pager.next_column_iterator().await {
+                Some(Ok(column_iterator)) => column_iterator,
+                Some(Err(err)) => return Err((rows_delivered, BridgedError::deserialization_failed(err))),
+                None => {
+                    exhausted = true;
+                    break;
+                }
+            };
+
             for value_index in 0..num_columns {
-                let raw_column = column_iterator.next().unwrap_or_else(|| {
-                    Err(DeserializationError::new(todo!(
-                        "Implement error type for too few columns - server provided less columns than claimed in the metadata"
-                    )))
-                }).unwrap(); // FIXME: handle error properly, passing it to C#.
+                let raw_column = match column_iterator.next() {
+                    Some(Ok(raw_column)) => raw_column,
+                    Some(Err(err)) => {
+                        return Err((rows_delivered, BridgedError::deserialization_failed(err)));
+                    }
+                    None => return Err((rows_delivered, BridgedError::too_few_columns())),
+                };
 
                 if let Some(frame_slice) = raw_column.slice {
                     unsafe {
                         deserialize_value(
                             columns_ptr,
                             values_ptr,
+                            rows_delivered,
                             value_index,
                             serializer_ptr,
                             frame_slice.as_slice().as_ptr(),
@@ -199,14 +379,26 @@ pub extern "C" fn row_set_next_row<'row_set>(
                     continue;
                 }
             }
-            true
-        } else {
-            println!("No more rows available!");
-            false
+
+            rows_delivered += 1;
+        }
+
+        Ok((rows_delivered, exhausted))
+    };
+
+    let (rows_delivered, exhausted) = match BridgedFuture::block_on(drain_fut) {
+        Ok(result) => result,
+        Err((rows_delivered, err)) => {
+            set_out_error(out_error, err);
+            (rows_delivered, false)
         }
     };
 
-    BridgedFuture::block_on(deserialize_fut) as i32
+    if !out_more_rows.is_null() {
+        unsafe { *out_more_rows = if exhausted { 0 } else { 1 } };
+    }
+
+    rows_delivered
 }
 
 // TODO: Below change all unwrap() to unwrap_or_else() with proper error handling
@@ -293,6 +485,53 @@ pub extern "C" fn row_set_type_info_get_set_child(
     }
 }
 
+/// Writes the element type of a `ColumnType::Vector` to `*out_child_handle` and
+/// returns 1, or returns 0 if `type_info_handle` is not a vector or
+/// `out_child_handle` is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_type_info_get_vector_element(
+    type_info_handle: BridgedOwnedExclusivePtr<ColumnType<'static>>,
+    out_child_handle: *mut BridgedOwnedExclusivePtr<ColumnType<'static>>,
+) -> i32 {
+    if type_info_handle.is_null() {
+        return 0;
+    }
+
+    let type_info = BoxFFI::as_ref(type_info_handle).unwrap();
+    match type_info {
+        ColumnType::Vector { typ, .. } => {
+            if out_child_handle.is_null() {
+                return 0;
+            }
+            let child = (*typ).as_ref().clone();
+            let boxed = Box::new(child);
+            let child_ptr = BoxFFI::into_ptr(boxed);
+            unsafe {
+                *out_child_handle = child_ptr;
+            }
+            1
+        }
+        _ => 0,
+    }
+}
+
+/// Returns the fixed dimensionality of a `ColumnType::Vector`, or 0 if
+/// `type_info_handle` is not a vector.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_set_type_info_get_vector_dimensions(
+    type_info_handle: BridgedOwnedExclusivePtr<ColumnType<'static>>,
+) -> usize {
+    if type_info_handle.is_null() {
+        return 0;
+    }
+
+    let type_info = BoxFFI::as_ref(type_info_handle).unwrap();
+    match type_info {
+        ColumnType::Vector { dimensions, .. } => *dimensions as usize,
+        _ => 0,
+    }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn row_set_type_info_get_map_children(
     type_info_handle: BridgedOwnedExclusivePtr<ColumnType<'static>>,
@@ -452,7 +691,7 @@ pub extern "C" fn row_set_type_info_get_udt_field(
     }
 }
 
-fn column_type_to_code(typ: &ColumnType) -> u16 {
+pub(crate) fn column_type_to_code(typ: &ColumnType) -> u16 {
     match typ {
         ColumnType::Native(nt) => match nt {
             NativeType::Ascii => 0x0001,
@@ -483,7 +722,7 @@ fn column_type_to_code(typ: &ColumnType) -> u16 {
             CollectionType::Set { .. } => 0x0022,
             _ => 0x0000,
         },
-        ColumnType::Vector { .. } => 0x0020,
+        ColumnType::Vector { .. } => 0x0023,
         ColumnType::UserDefinedType { .. } => 0x0030,
         ColumnType::Tuple(_) => 0x0031,
         _ => 0x0000,
@@ -1,8 +1,74 @@
 use scylla::serialize::writers::{CellValueBuilder, CellWriter, RowWriter};
-use std::ffi::{c_void};
+use std::cell::RefCell;
+use std::ffi::{CString, c_void};
+use std::fmt::Display;
 use std::ptr;
 use std::slice;
 
+// ============================================================================
+// Status codes and last-error channel
+// ============================================================================
+//
+// The writer FFI used to overload `1`/`0`/`-1` returns, so a caller couldn't tell a
+// null writer pointer from null data from an oversized value from a genuine
+// `SerializationError` from the driver. `WriterStatus` gives each of those its own
+// code, and `LAST_ERROR` carries the `Display` text of the underlying error (e.g. a
+// `SafeCell::Bytes` `set_value` failure) for whichever status needs one.
+
+/// Status codes returned by the `cell_writer_*`/`cell_value_builder_*` FFI below.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriterStatus {
+    Ok = 0,
+    NullWriter = 1,
+    NullData = 2,
+    CellOverflow = 3,
+    SerializationFailed = 4,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Records `message` as the current thread's last writer-FFI error.
+pub(crate) fn set_last_error(message: impl Display) {
+    let message = CString::new(message.to_string())
+        .unwrap_or_else(|_| CString::new("<error message contained a NUL byte>").unwrap());
+    LAST_ERROR.with(|cell| *cell.borrow_mut() = Some(message));
+}
+
+fn clear_last_error() {
+    LAST_ERROR.with(|cell| *cell.borrow_mut() = None);
+}
+
+/// Returns the `Display` text of the most recent writer-FFI failure on this thread, if
+/// any, by pointing `out_ptr`/`out_len` at it. The pointer is valid only until the next
+/// writer-FFI call on this thread - callers needing to retain the message must copy it
+/// out immediately.
+///
+/// Returns 1 if a message was available, 0 if no failure is on record.
+#[unsafe(no_mangle)]
+pub extern "C" fn csharp_driver_last_error_message(
+    out_ptr: *mut *const u8,
+    out_len: *mut usize,
+) -> i32 {
+    LAST_ERROR.with(|cell| match cell.borrow().as_ref() {
+        Some(message) => {
+            let bytes = message.as_bytes();
+            unsafe {
+                if !out_ptr.is_null() {
+                    *out_ptr = bytes.as_ptr();
+                }
+                if !out_len.is_null() {
+                    *out_len = bytes.len();
+                }
+            }
+            1
+        }
+        None => 0,
+    })
+}
+
 // ============================================================================
 // RowWriter FFI
 // ============================================================================
@@ -60,43 +126,115 @@ pub extern "C" fn row_writer_make_cell_writer(writer: *mut c_void) -> *mut c_voi
     }
 }
 
+/// Largest `count` `row_writer_append_values` accepts - reserves enough headroom below
+/// `i32::MAX` that every failing index (encoded as `-(index as i32) - 2`, see below)
+/// still fits in an `i32`.
+const MAX_APPEND_COUNT: usize = i32::MAX as usize - 2;
+
+/// Appends `count` cells to the row in one call, collapsing what would otherwise be
+/// `count` separate `row_writer_make_cell_writer` + `cell_writer_set_*` FFI round
+/// trips - the dominant cost when binding rows with many columns.
+///
+/// `kinds[i]` selects how `data_ptrs[i]`/`lens[i]` are interpreted for cell `i`:
+/// - `0` - a value cell; `data_ptrs[i]` must point at `lens[i]` bytes (or be null with
+///   `lens[i] == 0`),
+/// - `1` - a NULL cell; `data_ptrs[i]`/`lens[i]` are ignored,
+/// - `2` - an UNSET cell; `data_ptrs[i]`/`lens[i]` are ignored.
+///
+/// Returns the number of cells successfully written, i.e. `count` on full success.
+/// Stops at the first cell whose value exceeds `i32::MAX` bytes or carries an
+/// unrecognized kind byte, returning `-(index as i32) - 2` for that cell's index
+/// without writing it - always negative, so it can't be mistaken for a successful
+/// partial write count. Returns `-1` if `count` itself is too large to encode a
+/// failing index for.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_writer_append_values(
+    writer: *mut c_void,
+    count: usize,
+    kinds: *const u8,
+    data_ptrs: *const *const u8,
+    lens: *const usize,
+) -> i32 {
+    if writer.is_null() {
+        return 0;
+    }
+    if count > MAX_APPEND_COUNT {
+        return -1;
+    }
+    if count > 0 && (kinds.is_null() || data_ptrs.is_null() || lens.is_null()) {
+        return 0;
+    }
+
+    unsafe {
+        let writer_ref = &mut *(writer as *mut RowWriter);
+
+        for index in 0..count {
+            // Validate before calling `make_cell_writer()` wherever possible, so a
+            // rejected cell never leaves a reserved-but-unfinished `CellWriter` behind
+            // in `writer_ref`.
+            match *kinds.add(index) {
+                0 => {
+                    let data = *data_ptrs.add(index);
+                    let len = *lens.add(index);
+                    if (data.is_null() && len > 0) || len > i32::MAX as usize {
+                        return -(index as i32) - 2;
+                    }
+                    let contents = if len == 0 {
+                        &[]
+                    } else {
+                        slice::from_raw_parts(data, len)
+                    };
+                    if writer_ref.make_cell_writer().set_value(contents).is_err() {
+                        return -(index as i32) - 2;
+                    }
+                }
+                1 => writer_ref.make_cell_writer().set_null(),
+                2 => writer_ref.make_cell_writer().set_unset(),
+                _ => return -(index as i32) - 2,
+            }
+        }
+
+        count as i32
+    }
+}
+
 // ============================================================================
 // CellWriter FFI
 // ============================================================================
 
 /// Sets the cell value to NULL and consumes the CellWriter.
-/// Returns 1 on success, 0 on failure.
+/// Returns a [`WriterStatus`] code.
 #[unsafe(no_mangle)]
 pub extern "C" fn cell_writer_set_null(writer: *mut c_void) -> i32 {
     if writer.is_null() {
-        return 0;
+        return WriterStatus::NullWriter as i32;
     }
+    clear_last_error();
     unsafe {
         let cell_writer = *Box::from_raw(writer as *mut CellWriter);
         let _proof = cell_writer.set_null();
-        1
     }
+    WriterStatus::Ok as i32
 }
 
 /// Sets the cell value to UNSET and consumes the CellWriter.
-/// Returns 1 on success, 0 on failure.
+/// Returns a [`WriterStatus`] code.
 #[unsafe(no_mangle)]
 pub extern "C" fn cell_writer_set_unset(writer: *mut c_void) -> i32 {
     if writer.is_null() {
-        return 0;
+        return WriterStatus::NullWriter as i32;
     }
+    clear_last_error();
     unsafe {
         let cell_writer = *Box::from_raw(writer as *mut CellWriter);
         let _proof = cell_writer.set_unset();
-        1
     }
+    WriterStatus::Ok as i32
 }
 
 /// Sets the cell value to the provided byte array and consumes the CellWriter.
-/// Returns:
-/// - 1 on success
-/// - 0 if writer is null or data is null with len > 0
-/// - -1 if the value size exceeds i32::MAX
+/// Returns a [`WriterStatus`] code; on `CellOverflow`, the `CellOverflowError`'s
+/// `Display` text is available via `csharp_driver_last_error_message`.
 #[unsafe(no_mangle)]
 pub extern "C" fn cell_writer_set_value(
     writer: *mut c_void,
@@ -104,12 +242,13 @@ pub extern "C" fn cell_writer_set_value(
     len: usize,
 ) -> i32 {
     if writer.is_null() {
-        return 0;
+        return WriterStatus::NullWriter as i32;
     }
     if data.is_null() && len > 0 {
-        return 0;
+        return WriterStatus::NullData as i32;
     }
 
+    clear_last_error();
     unsafe {
         let cell_writer = *Box::from_raw(writer as *mut CellWriter);
         let contents = if len == 0 {
@@ -119,8 +258,11 @@ pub extern "C" fn cell_writer_set_value(
         };
 
         match cell_writer.set_value(contents) {
-            Ok(_proof) => 1,
-            Err(_) => -1, // CellOverflowError
+            Ok(_proof) => WriterStatus::Ok as i32,
+            Err(err) => {
+                set_last_error(err);
+                WriterStatus::CellOverflow as i32
+            }
         }
     }
 }
@@ -145,10 +287,7 @@ pub extern "C" fn cell_writer_into_value_builder(writer: *mut c_void) -> *mut c_
 // ============================================================================
 
 /// Appends data to the cell value being built.
-/// Returns:
-/// - 1 on success
-/// - 0 if builder or data is null
-/// - -1 if the total size would exceed i32::MAX
+/// Returns a [`WriterStatus`] code.
 #[unsafe(no_mangle)]
 pub extern "C" fn cell_value_builder_append(
     builder: *mut c_void,
@@ -156,10 +295,10 @@ pub extern "C" fn cell_value_builder_append(
     len: usize,
 ) -> i32 {
     if builder.is_null() {
-        return 0;
+        return WriterStatus::NullWriter as i32;
     }
     if data.is_null() && len > 0 {
-        return 0;
+        return WriterStatus::NullData as i32;
     }
 
     unsafe {
@@ -171,25 +310,208 @@ pub extern "C" fn cell_value_builder_append(
         };
 
         builder_ref.append_bytes(contents);
-        1
     }
+    WriterStatus::Ok as i32
 }
 
 /// Finishes building the cell value and consumes the CellValueBuilder.
-/// Returns 1 on success, 0 on failure.
+/// Returns a [`WriterStatus`] code.
 #[unsafe(no_mangle)]
 pub extern "C" fn cell_value_builder_finish(builder: *mut c_void) -> i32 {
     if builder.is_null() {
-        return 0;
+        return WriterStatus::NullWriter as i32;
     }
+    clear_last_error();
     unsafe {
         let cell_builder = *Box::from_raw(builder as *mut CellValueBuilder);
         let _proof = cell_builder.finish();
-        1
     }
+    WriterStatus::Ok as i32
 }
 
 
+// ============================================================================
+// Measuring RowWriter FFI - a parallel, allocation-free sizing pass
+// ============================================================================
+//
+// Mirrors the RowWriter/CellWriter/CellValueBuilder surface above, but instead of
+// copying value bytes into a buffer it only accumulates the total length that the
+// real write would produce. The intended workflow is: run a measuring pass over the
+// cells, read `measuring_row_writer_total_len()`, then allocate a single
+// `serialized_row_new_with_capacity(len)` buffer so the real write never reallocates.
+//
+// The measuring and writing passes must visit cells in the same order - the total is
+// just a running sum, not keyed by cell identity. Every cell, including a zero-length
+// value, contributes a 4-byte length prefix (CQL's `[value]` format: -1 for NULL, -2
+// for UNSET, otherwise the byte length) in addition to its value bytes.
+
+const VALUE_LENGTH_PREFIX_SIZE: usize = 4;
+
+/// Accumulates the total serialized length of a row without allocating or copying any
+/// value bytes. The measuring counterpart to `RowWriter`.
+#[derive(Default)]
+pub struct MeasuringRowWriter {
+    total_len: usize,
+    value_count: usize,
+}
+
+/// Creates a new measuring row writer. Must be freed with `measuring_row_writer_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_row_writer_new() -> *mut c_void {
+    Box::into_raw(Box::new(MeasuringRowWriter::default())) as *mut c_void
+}
+
+/// Frees a measuring row writer.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_row_writer_free(writer: *mut c_void) {
+    if writer.is_null() {
+        return;
+    }
+    unsafe {
+        let _ = Box::from_raw(writer as *mut MeasuringRowWriter);
+    }
+}
+
+/// Returns the number of values accounted for so far.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_row_writer_value_count(writer: *const c_void) -> usize {
+    if writer.is_null() {
+        return 0;
+    }
+    unsafe { (*(writer as *const MeasuringRowWriter)).value_count }
+}
+
+/// Returns the total serialized length accounted for so far, in bytes.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_row_writer_total_len(writer: *const c_void) -> usize {
+    if writer.is_null() {
+        return 0;
+    }
+    unsafe { (*(writer as *const MeasuringRowWriter)).total_len }
+}
+
+/// A single value's pending contribution to a `MeasuringRowWriter`'s total length.
+/// Must be consumed by exactly one of the `measuring_cell_writer_*` functions below,
+/// mirroring `CellWriter`'s contract.
+struct MeasuringCellWriter {
+    writer: *mut MeasuringRowWriter,
+}
+
+/// Creates a new `MeasuringCellWriter` for the next value in the row.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_row_writer_make_cell_writer(writer: *mut c_void) -> *mut c_void {
+    if writer.is_null() {
+        return ptr::null_mut();
+    }
+    let cell_writer = MeasuringCellWriter {
+        writer: writer as *mut MeasuringRowWriter,
+    };
+    Box::into_raw(Box::new(cell_writer)) as *mut c_void
+}
+
+impl MeasuringCellWriter {
+    /// Accounts `value_len` bytes of value content (0 for NULL/UNSET) plus the 4-byte
+    /// length prefix every cell contributes, and consumes `self`.
+    fn commit(self, value_len: usize) {
+        unsafe {
+            let writer = &mut *self.writer;
+            writer.total_len += VALUE_LENGTH_PREFIX_SIZE + value_len;
+            writer.value_count += 1;
+        }
+    }
+}
+
+/// Accounts for a NULL cell and consumes the `MeasuringCellWriter`.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_cell_writer_set_null(writer: *mut c_void) -> i32 {
+    if writer.is_null() {
+        return 0;
+    }
+    unsafe { *Box::from_raw(writer as *mut MeasuringCellWriter) }.commit(0);
+    1
+}
+
+/// Accounts for an UNSET cell and consumes the `MeasuringCellWriter`.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_cell_writer_set_unset(writer: *mut c_void) -> i32 {
+    if writer.is_null() {
+        return 0;
+    }
+    unsafe { *Box::from_raw(writer as *mut MeasuringCellWriter) }.commit(0);
+    1
+}
+
+/// Accounts for a value cell of `len` bytes and consumes the `MeasuringCellWriter`.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_cell_writer_set_value(
+    writer: *mut c_void,
+    data: *const u8,
+    len: usize,
+) -> i32 {
+    if writer.is_null() {
+        return 0;
+    }
+    if data.is_null() && len > 0 {
+        return 0;
+    }
+    unsafe { *Box::from_raw(writer as *mut MeasuringCellWriter) }.commit(len);
+    1
+}
+
+/// A cell value being measured incrementally via repeated `measuring_cell_value_builder_append` calls.
+struct MeasuringCellValueBuilder {
+    writer: *mut MeasuringRowWriter,
+    len: usize,
+}
+
+/// Converts the `MeasuringCellWriter` into a `MeasuringCellValueBuilder` for gradual
+/// accounting, mirroring `cell_writer_into_value_builder`. Must be finished with
+/// `measuring_cell_value_builder_finish`.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_cell_writer_into_value_builder(writer: *mut c_void) -> *mut c_void {
+    if writer.is_null() {
+        return ptr::null_mut();
+    }
+    let cell_writer = unsafe { *Box::from_raw(writer as *mut MeasuringCellWriter) };
+    let builder = MeasuringCellValueBuilder {
+        writer: cell_writer.writer,
+        len: 0,
+    };
+    Box::into_raw(Box::new(builder)) as *mut c_void
+}
+
+/// Accounts `len` more bytes toward the value being built.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_cell_value_builder_append(
+    builder: *mut c_void,
+    data: *const u8,
+    len: usize,
+) -> i32 {
+    if builder.is_null() {
+        return 0;
+    }
+    if data.is_null() && len > 0 {
+        return 0;
+    }
+    unsafe { (*(builder as *mut MeasuringCellValueBuilder)).len += len };
+    1
+}
+
+/// Finishes accounting for the built value and consumes the `MeasuringCellValueBuilder`.
+#[unsafe(no_mangle)]
+pub extern "C" fn measuring_cell_value_builder_finish(builder: *mut c_void) -> i32 {
+    if builder.is_null() {
+        return 0;
+    }
+    let builder = unsafe { *Box::from_raw(builder as *mut MeasuringCellValueBuilder) };
+    unsafe {
+        let writer = &mut *builder.writer;
+        writer.total_len += VALUE_LENGTH_PREFIX_SIZE + builder.len;
+        writer.value_count += 1;
+    }
+    1
+}
+
 // ============================================================================
 // Helper for buffer management
 // ============================================================================
@@ -219,6 +541,23 @@ pub extern "C" fn serialized_row_new() -> *mut SerializedRow {
     Box::into_raw(Box::new(serialized))
 }
 
+/// Creates a new row buffer pre-allocated to hold exactly `capacity` bytes.
+///
+/// Intended to be sized from a prior `measuring_row_writer_total_len()` result so the
+/// real write pass (via `serialized_row_get_writer`) never needs to grow the buffer.
+#[unsafe(no_mangle)]
+pub extern "C" fn serialized_row_new_with_capacity(capacity: usize) -> *mut SerializedRow {
+    let vec = Vec::<u8>::with_capacity(capacity);
+    let serialized = SerializedRow {
+        data: vec.as_ptr() as *mut u8,
+        len: vec.len(),
+        capacity: vec.capacity(),
+        leaked_vec: ptr::null_mut(), // No Vec leaked yet
+    };
+    std::mem::forget(vec); // Prevent Vec from being dropped
+    Box::into_raw(Box::new(serialized))
+}
+
 /// Gets a RowWriter for the SerializedRow.
 /// The RowWriter borrows the buffer mutably.
 /// Note: This function leaks the Vec buffer to ensure it has a 'static lifetime.
@@ -295,15 +634,437 @@ pub extern "C" fn serialized_row_free(row: *mut SerializedRow) {
     if row.is_null() {
         return;
     }
-    
+
     unsafe {
         let row_box = Box::from_raw(row);
         // If we have a leaked Vec, reconstruct and drop it
         if !row_box.leaked_vec.is_null() {
             let _ = Box::from_raw(row_box.leaked_vec);
-        } else {
-            // Otherwise reconstruct and drop from raw parts
+        } else if !row_box.data.is_null() {
+            // Otherwise reconstruct and drop from raw parts. `Vec::from_raw_parts`
+            // requires a non-null pointer even for a zero-capacity Vec - `data` is null
+            // after `serialized_row_take_buffer` has transferred the buffer out, so
+            // there's nothing left here to reconstruct and drop.
             let _ = Vec::from_raw_parts(row_box.data, row_box.len, row_box.capacity);
         }
     }
 }
+
+/// Transfers ownership of `row`'s backing buffer to the caller via its raw parts and
+/// leaves `row` empty, instead of the "valid until `serialized_row_free`" pointer
+/// `serialized_row_get_data` hands out. The returned buffer must be released with
+/// `serialized_buffer_free`, independently of `row`'s own lifetime; `row` itself must
+/// still be freed separately with `serialized_row_free`.
+///
+/// Returns 1 on success, 0 if `row` or any out-parameter is null.
+#[unsafe(no_mangle)]
+pub extern "C" fn serialized_row_take_buffer(
+    row: *mut SerializedRow,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+    out_cap: *mut usize,
+) -> i32 {
+    if row.is_null() || out_ptr.is_null() || out_len.is_null() || out_cap.is_null() {
+        return 0;
+    }
+
+    unsafe {
+        let row_ref = &mut *row;
+
+        let (data, len, capacity) = if !row_ref.leaked_vec.is_null() {
+            let mut vec = *Box::from_raw(row_ref.leaked_vec);
+            let data = vec.as_mut_ptr();
+            let len = vec.len();
+            let capacity = vec.capacity();
+            std::mem::forget(vec);
+            (data, len, capacity)
+        } else {
+            (row_ref.data, row_ref.len, row_ref.capacity)
+        };
+
+        *out_ptr = data;
+        *out_len = len;
+        *out_cap = capacity;
+
+        row_ref.data = ptr::null_mut();
+        row_ref.len = 0;
+        row_ref.capacity = 0;
+        row_ref.leaked_vec = ptr::null_mut();
+    }
+
+    1
+}
+
+/// Reconstructs and drops a buffer previously obtained from
+/// `serialized_row_take_buffer`. `len`/`cap` must be the exact values returned
+/// alongside `ptr` - mismatched values are undefined behavior, same as
+/// `Vec::from_raw_parts`.
+#[unsafe(no_mangle)]
+pub extern "C" fn serialized_buffer_free(ptr: *mut u8, len: usize, cap: usize) {
+    if ptr.is_null() {
+        return;
+    }
+    unsafe {
+        let _ = Vec::from_raw_parts(ptr, len, cap);
+    }
+}
+
+// ============================================================================
+// RowReader FFI - parsing a serialized row buffer back into cells
+// ============================================================================
+//
+// The inverse of the RowWriter above: walks a borrowed byte slice using the CQL
+// `[value]` wire format (a 4-byte big-endian signed length, followed by that many
+// bytes of content when the length is non-negative), classifying each value the same
+// way the driver's `RawValue` does: -1 is NULL, -2 is UNSET, and a non-negative length
+// is a value of that many bytes.
+
+/// Kinds a `row_reader_next_value` read can classify a value as. Mirrors the driver's
+/// `RawValue`.
+#[repr(i32)]
+pub enum ValueKind {
+    Null = 0,
+    Unset = 1,
+    Value = 2,
+}
+
+/// Return codes for `row_reader_next_value`.
+const ROW_READER_END_OF_BUFFER: i32 = 0;
+const ROW_READER_VALUE_READ: i32 = 1;
+const ROW_READER_MALFORMED: i32 = -1;
+
+/// Walks a borrowed byte slice one length-prefixed value at a time. Does not own or
+/// copy the underlying bytes - the caller must keep them alive for the reader's
+/// lifetime.
+pub struct RowReader {
+    data: *const u8,
+    len: usize,
+    pos: usize,
+}
+
+/// Wraps a borrowed `data[..len]` slice for reading. The caller must keep the buffer
+/// alive until the reader is freed with `row_reader_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_reader_new(data: *const u8, len: usize) -> *mut c_void {
+    if data.is_null() && len > 0 {
+        return ptr::null_mut();
+    }
+    Box::into_raw(Box::new(RowReader { data, len, pos: 0 })) as *mut c_void
+}
+
+/// Frees a `RowReader`. Does not touch the borrowed buffer it was reading from.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_reader_free(reader: *mut c_void) {
+    if reader.is_null() {
+        return;
+    }
+    unsafe {
+        let _ = Box::from_raw(reader as *mut RowReader);
+    }
+}
+
+/// Reads the next length-prefixed value from the buffer.
+///
+/// On a successful read, writes the value's kind to `out_kind`; for `ValueKind::Value`
+/// also writes an interior pointer into the reader's buffer to `out_ptr` and the
+/// value's byte length to `out_len` (both left untouched for NULL/UNSET). Returns:
+/// - `1` if a value was read,
+/// - `0` if the buffer has been fully consumed,
+/// - `-1` if the length prefix is truncated, or declares a length that would run past
+///   the end of the buffer.
+///
+/// A malformed read does not advance the reader, so the same error will be reported
+/// again if called a second time without resetting the buffer.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_reader_next_value(
+    reader: *mut c_void,
+    out_kind: *mut i32,
+    out_ptr: *mut *const u8,
+    out_len: *mut usize,
+) -> i32 {
+    if reader.is_null() {
+        return ROW_READER_MALFORMED;
+    }
+    unsafe {
+        let reader_ref = &mut *(reader as *mut RowReader);
+
+        if reader_ref.pos == reader_ref.len {
+            return ROW_READER_END_OF_BUFFER;
+        }
+        if reader_ref.len - reader_ref.pos < 4 {
+            return ROW_READER_MALFORMED;
+        }
+
+        let prefix = slice::from_raw_parts(reader_ref.data.add(reader_ref.pos), 4);
+        let declared_len = i32::from_be_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]);
+
+        match declared_len {
+            -1 => {
+                reader_ref.pos += 4;
+                if !out_kind.is_null() {
+                    *out_kind = ValueKind::Null as i32;
+                }
+                ROW_READER_VALUE_READ
+            }
+            -2 => {
+                reader_ref.pos += 4;
+                if !out_kind.is_null() {
+                    *out_kind = ValueKind::Unset as i32;
+                }
+                ROW_READER_VALUE_READ
+            }
+            declared_len if declared_len >= 0 => {
+                let value_len = declared_len as usize;
+                if value_len > reader_ref.len - reader_ref.pos - 4 {
+                    return ROW_READER_MALFORMED;
+                }
+                let value_ptr = reader_ref.data.add(reader_ref.pos + 4);
+                reader_ref.pos += 4 + value_len;
+                if !out_kind.is_null() {
+                    *out_kind = ValueKind::Value as i32;
+                }
+                if !out_ptr.is_null() {
+                    *out_ptr = value_ptr;
+                }
+                if !out_len.is_null() {
+                    *out_len = value_len;
+                }
+                ROW_READER_VALUE_READ
+            }
+            _ => ROW_READER_MALFORMED,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measuring_row_writer_matches_actual_length_for_null_unset_value_and_builder_cells() {
+        let value = b"hello";
+
+        let measuring = measuring_row_writer_new();
+        assert_eq!(
+            measuring_cell_writer_set_null(measuring_row_writer_make_cell_writer(measuring)),
+            1
+        );
+        assert_eq!(
+            measuring_cell_writer_set_unset(measuring_row_writer_make_cell_writer(measuring)),
+            1
+        );
+        assert_eq!(
+            measuring_cell_writer_set_value(
+                measuring_row_writer_make_cell_writer(measuring),
+                value.as_ptr(),
+                value.len()
+            ),
+            1
+        );
+        let builder =
+            measuring_cell_writer_into_value_builder(measuring_row_writer_make_cell_writer(measuring));
+        assert_eq!(
+            measuring_cell_value_builder_append(builder, value.as_ptr(), value.len()),
+            1
+        );
+        assert_eq!(measuring_cell_value_builder_finish(builder), 1);
+
+        assert_eq!(measuring_row_writer_value_count(measuring), 4);
+        let measured_len = measuring_row_writer_total_len(measuring);
+        measuring_row_writer_free(measuring);
+
+        let row = serialized_row_new_with_capacity(measured_len);
+        let writer = serialized_row_get_writer(row);
+        assert_eq!(
+            cell_writer_set_null(row_writer_make_cell_writer(writer)),
+            WriterStatus::Ok as i32
+        );
+        assert_eq!(
+            cell_writer_set_unset(row_writer_make_cell_writer(writer)),
+            WriterStatus::Ok as i32
+        );
+        assert_eq!(
+            cell_writer_set_value(row_writer_make_cell_writer(writer), value.as_ptr(), value.len()),
+            WriterStatus::Ok as i32
+        );
+        let real_builder = cell_writer_into_value_builder(row_writer_make_cell_writer(writer));
+        assert_eq!(
+            cell_value_builder_append(real_builder, value.as_ptr(), value.len()),
+            WriterStatus::Ok as i32
+        );
+        assert_eq!(cell_value_builder_finish(real_builder), WriterStatus::Ok as i32);
+        row_writer_free(writer);
+
+        let mut data_ptr = ptr::null();
+        let mut actual_len = 0usize;
+        assert_eq!(serialized_row_get_data(row, &mut data_ptr, &mut actual_len), 1);
+        assert_eq!(actual_len, measured_len);
+
+        serialized_row_free(row);
+    }
+
+    #[test]
+    fn row_writer_append_values_bulk_matches_one_at_a_time() {
+        // kind 1 = null, kind 2 = unset, kind 0 = value (including a zero-length value).
+        let cells: Vec<(u8, Vec<u8>)> = vec![
+            (1, vec![]),
+            (2, vec![]),
+            (0, b"hello".to_vec()),
+            (0, vec![]),
+        ];
+
+        let row_one_at_a_time = serialized_row_new();
+        let writer = serialized_row_get_writer(row_one_at_a_time);
+        for (kind, data) in &cells {
+            let cell_writer = row_writer_make_cell_writer(writer);
+            match kind {
+                1 => {
+                    cell_writer_set_null(cell_writer);
+                }
+                2 => {
+                    cell_writer_set_unset(cell_writer);
+                }
+                _ => {
+                    cell_writer_set_value(cell_writer, data.as_ptr(), data.len());
+                }
+            }
+        }
+        row_writer_free(writer);
+        let mut ptr_a = ptr::null();
+        let mut len_a = 0usize;
+        serialized_row_get_data(row_one_at_a_time, &mut ptr_a, &mut len_a);
+        let bytes_one_at_a_time = unsafe { slice::from_raw_parts(ptr_a, len_a) }.to_vec();
+
+        let row_bulk = serialized_row_new();
+        let writer = serialized_row_get_writer(row_bulk);
+        let kinds: Vec<u8> = cells.iter().map(|(kind, _)| *kind).collect();
+        let data_ptrs: Vec<*const u8> = cells
+            .iter()
+            .map(|(_, data)| if data.is_empty() { ptr::null() } else { data.as_ptr() })
+            .collect();
+        let lens: Vec<usize> = cells.iter().map(|(_, data)| data.len()).collect();
+        let written = row_writer_append_values(
+            writer,
+            cells.len(),
+            kinds.as_ptr(),
+            data_ptrs.as_ptr(),
+            lens.as_ptr(),
+        );
+        assert_eq!(written, cells.len() as i32);
+        row_writer_free(writer);
+        let mut ptr_b = ptr::null();
+        let mut len_b = 0usize;
+        serialized_row_get_data(row_bulk, &mut ptr_b, &mut len_b);
+        let bytes_bulk = unsafe { slice::from_raw_parts(ptr_b, len_b) }.to_vec();
+
+        assert_eq!(bytes_one_at_a_time, bytes_bulk);
+
+        serialized_row_free(row_one_at_a_time);
+        serialized_row_free(row_bulk);
+    }
+
+    #[test]
+    fn row_writer_append_values_reports_a_distinct_negative_code_for_the_failing_index() {
+        // Cell 0 is a valid value; cell 1 carries an unrecognized kind byte and should
+        // fail without being mistaken for "2 cells successfully written".
+        let kinds = [0u8, 9u8];
+        let data_ptrs = [ptr::null::<u8>(), ptr::null::<u8>()];
+        let lens = [0usize, 0usize];
+
+        let row = serialized_row_new();
+        let writer = serialized_row_get_writer(row);
+        let result = row_writer_append_values(writer, 2, kinds.as_ptr(), data_ptrs.as_ptr(), lens.as_ptr());
+
+        assert_eq!(result, -3); // -(1) - 2, identifying index 1
+        assert!(result < 0);
+
+        row_writer_free(writer);
+        serialized_row_free(row);
+    }
+
+    #[test]
+    fn serialized_row_take_buffer_matches_get_data_and_does_not_double_free() {
+        let value = b"row-bytes";
+
+        let row = serialized_row_new();
+        let writer = serialized_row_get_writer(row);
+        cell_writer_set_value(row_writer_make_cell_writer(writer), value.as_ptr(), value.len());
+        row_writer_free(writer);
+
+        let mut data_ptr = ptr::null();
+        let mut data_len = 0usize;
+        assert_eq!(serialized_row_get_data(row, &mut data_ptr, &mut data_len), 1);
+        let expected_bytes = unsafe { slice::from_raw_parts(data_ptr, data_len) }.to_vec();
+
+        let mut out_ptr = ptr::null_mut();
+        let mut out_len = 0usize;
+        let mut out_cap = 0usize;
+        assert_eq!(
+            serialized_row_take_buffer(row, &mut out_ptr, &mut out_len, &mut out_cap),
+            1
+        );
+        assert_eq!(out_len, expected_bytes.len());
+        let taken_bytes = unsafe { slice::from_raw_parts(out_ptr, out_len) }.to_vec();
+        assert_eq!(taken_bytes, expected_bytes);
+
+        // `row` must no longer think it owns a buffer, so freeing it doesn't also free
+        // the buffer we just took - otherwise this would be a double free.
+        serialized_row_free(row);
+
+        serialized_buffer_free(out_ptr, out_len, out_cap);
+    }
+
+    #[test]
+    fn writer_status_null_writer_and_null_data_report_expected_codes() {
+        assert_eq!(
+            cell_writer_set_value(ptr::null_mut(), ptr::null(), 0),
+            WriterStatus::NullWriter as i32
+        );
+        assert_eq!(
+            cell_writer_set_null(ptr::null_mut()),
+            WriterStatus::NullWriter as i32
+        );
+        assert_eq!(
+            cell_writer_set_unset(ptr::null_mut()),
+            WriterStatus::NullWriter as i32
+        );
+
+        let row = serialized_row_new();
+        let writer = serialized_row_get_writer(row);
+        let cell_writer = row_writer_make_cell_writer(writer);
+        assert_eq!(
+            cell_writer_set_value(cell_writer, ptr::null(), 5),
+            WriterStatus::NullData as i32
+        );
+
+        row_writer_free(writer);
+        serialized_row_free(row);
+    }
+
+    #[test]
+    fn writer_status_cell_overflow_reports_expected_code_and_non_empty_message() {
+        // `CellWriter::set_value` rejects a value whose length doesn't fit in the CQL
+        // `[value]` format's signed 32-bit length prefix. Reserve (but don't initialize)
+        // a buffer one byte past `i32::MAX` to trigger that without actually writing
+        // 2GiB of data.
+        let oversized_len = i32::MAX as usize + 1;
+        let mut oversized = Vec::<u8>::with_capacity(oversized_len);
+        unsafe { oversized.set_len(oversized_len) };
+
+        let row = serialized_row_new();
+        let writer = serialized_row_get_writer(row);
+        let cell_writer = row_writer_make_cell_writer(writer);
+        assert_eq!(
+            cell_writer_set_value(cell_writer, oversized.as_ptr(), oversized.len()),
+            WriterStatus::CellOverflow as i32
+        );
+
+        let mut out_ptr = ptr::null();
+        let mut out_len = 0usize;
+        assert_eq!(csharp_driver_last_error_message(&mut out_ptr, &mut out_len), 1);
+        assert!(out_len > 0);
+
+        row_writer_free(writer);
+        serialized_row_free(row);
+    }
+}
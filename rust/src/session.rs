@@ -3,11 +3,13 @@ use scylla::client::session_builder::SessionBuilder;
 use scylla::errors::{NewSessionError, PagerExecutionError, PrepareError};
 
 use crate::CSharpStr;
+use crate::error::NotYetSupported;
 use crate::ffi::{ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FromArc, BoxFFI, BridgedOwnedExclusivePtr};
 use crate::prepared_statement::BridgedPreparedStatement;
 use crate::row_set::RowSet;
-use crate::task::{BridgedFuture, Tcb};
+use crate::task::{BridgedFuture, CancellationHandle, Tcb};
 use crate::pre_serialized_values::PreSerializedValues;
+use crate::typed_values::TypedValues;
 
 impl FFI for BridgedSession {
     type Origin = FromArc;
@@ -19,18 +21,21 @@ pub struct BridgedSession {
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn session_create(tcb: Tcb, uri: CSharpStr<'_>) {
+pub extern "C" fn session_create(
+    tcb: Tcb,
+    uri: CSharpStr<'_>,
+) -> BridgedOwnedExclusivePtr<CancellationHandle> {
     // Convert the raw C string to a Rust string
     let uri = uri.as_cstr().unwrap().to_str().unwrap();
     let uri = uri.to_owned();
 
     BridgedFuture::spawn::<_, _, NewSessionError>(tcb, async move {
-        println!("Create Session... {}", uri);
+        tracing::info!(node = %uri, "creating session");
         let session = SessionBuilder::new().known_node(&uri).build().await?;
-        println!("Session created! {}", uri);
-        println!(
-            "Contacted node's address: {}",
-            session.get_cluster_state().get_nodes_info()[0].address
+        tracing::info!(
+            node = %uri,
+            contacted_address = %session.get_cluster_state().get_nodes_info()[0].address,
+            "session created"
         );
         Ok(BridgedSession { inner: session })
     })
@@ -39,7 +44,7 @@ pub extern "C" fn session_create(tcb: Tcb, uri: CSharpStr<'_>) {
 #[unsafe(no_mangle)]
 pub extern "C" fn session_free(session_ptr: BridgedOwnedSharedPtr<BridgedSession>) {
     ArcFFI::free(session_ptr);
-    println!("Session freed");
+    tracing::debug!("session freed");
 }
 
 #[unsafe(no_mangle)]
@@ -47,17 +52,17 @@ pub extern "C" fn session_prepare(
     tcb: Tcb,
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
     statement: CSharpStr<'_>,
-) {
+) -> BridgedOwnedExclusivePtr<CancellationHandle> {
     // Convert the raw C string to a Rust string.
     let statement = statement.as_cstr().unwrap().to_str().unwrap().to_owned();
     let bridged_session = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
 
-    println!("Scheduling statement for preparation: \"{}\"", statement);
+    tracing::debug!(statement = %statement, "scheduling statement for preparation");
 
     BridgedFuture::spawn::<_, _, PrepareError>(tcb, async move {
-        println!("Preparing statement \"{}\"", statement);
+        tracing::info!(statement = %statement, "preparing statement");
         let ps = bridged_session.inner.prepare(statement).await?;
-        println!("Statement prepared");
+        tracing::debug!("statement prepared");
 
         Ok(BridgedPreparedStatement { inner: ps })
     })
@@ -68,22 +73,22 @@ pub extern "C" fn session_query(
     tcb: Tcb,
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
     statement: CSharpStr<'_>,
-) {
+) -> BridgedOwnedExclusivePtr<CancellationHandle> {
     let statement = statement.as_cstr().unwrap().to_str().unwrap().to_owned();
     let bridged_session = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
 
-    println!("Scheduling statement for execution: \"{}\"", statement);
+    tracing::debug!(statement = %statement, "scheduling statement for execution");
 
     BridgedFuture::spawn::<_, _, PagerExecutionError>(tcb, async move {
-        println!("Executing statement \"{}\"", statement);
+        tracing::info!(statement = %statement, "executing statement");
         // Query with no values: use the unit `()` which implements `SerializeRow` as empty.
         let query_pager = bridged_session.inner.query_iter(statement, ()).await?;
-        println!("Statement executed");
+        tracing::debug!("statement executed");
 
         Ok(RowSet {
             pager: std::sync::Mutex::new(query_pager),
         })
-    });
+    })
 }
 
 
@@ -94,7 +99,7 @@ pub extern "C" fn session_query_with_values(
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
     statement: CSharpStr<'_>,
     values_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
-) {
+) -> BridgedOwnedExclusivePtr<CancellationHandle> {
     // Convert the raw C string to a Rust string.
     let statement = statement.as_cstr().unwrap().to_str().unwrap().to_owned();
     let bridged_session = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
@@ -102,24 +107,119 @@ pub extern "C" fn session_query_with_values(
     // Take ownership of the pre-serialized values box so we can move it into the async task.
     let values_box = BoxFFI::from_ptr(values_ptr).expect("non-null PreSerializedValues pointer");
 
-    println!("Scheduling statement for execution with values: \"{}\"", statement);
-
-    // Capture the number of values before moving the box into the async task so we can print it after scheduling.
+    // Capture the number of values before moving the box into the async task so we can log it after scheduling.
     let values_count = values_box.len();
 
-    // Debug: print the number of pre-serialized values that were scheduled with the statement.
-    println!("Scheduled statement with {} pre-serialized value(s)", values_count);
+    tracing::debug!(
+        statement = %statement,
+        value_count = values_count,
+        "scheduling statement for execution with values"
+    );
 
     BridgedFuture::spawn::<_, _, PagerExecutionError>(tcb, async move {
-        println!("Executing statement with values \"{}\"", statement);
+        tracing::info!(statement = %statement, value_count = values_count, "executing statement with values");
         // Pass a reference to the PreSerializedValues implementing SerializeRow.
 
         //TODO: query_iter is discouraged for the use with parameters, investigate this
         let query_pager = bridged_session.inner.query_iter(statement, &*values_box).await?;
-        println!("Statement executed");
+        tracing::debug!("statement executed");
+
+        Ok(RowSet {
+            pager: std::sync::Mutex::new(query_pager),
+        })
+    })
+}
+
+// I duplicated code since it's meant to be refactored anyway
+/// Like `session_query_with_values`, but bound to a `TypedValues` builder instead of a
+/// pre-serialized one - the values are converted to CQL cells on the Rust side as they're
+/// added, so the caller never has to hand over already-serialized bytes.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_query_with_typed_values(
+    tcb: Tcb,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    statement: CSharpStr<'_>,
+    values_ptr: BridgedOwnedExclusivePtr<TypedValues>,
+) -> BridgedOwnedExclusivePtr<CancellationHandle> {
+    // Convert the raw C string to a Rust string.
+    let statement = statement.as_cstr().unwrap().to_str().unwrap().to_owned();
+    let bridged_session = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    // Take ownership of the typed values box so we can move it into the async task.
+    let values_box = BoxFFI::from_ptr(values_ptr).expect("non-null TypedValues pointer");
+
+    // Capture the number of values before moving the box into the async task so we can log it after scheduling.
+    let values_count = values_box.len();
+
+    tracing::debug!(
+        statement = %statement,
+        value_count = values_count,
+        "scheduling statement for execution with typed values"
+    );
+
+    BridgedFuture::spawn::<_, _, PagerExecutionError>(tcb, async move {
+        tracing::info!(statement = %statement, value_count = values_count, "executing statement with typed values");
+
+        //TODO: query_iter is discouraged for the use with parameters, investigate this
+        let query_pager = bridged_session.inner.query_iter(statement, &*values_box).await?;
+        tracing::debug!("statement executed");
 
         Ok(RowSet {
             pager: std::sync::Mutex::new(query_pager),
         })
-    });
+    })
+}
+
+/// Entry point for resuming a query from a paging-state blob. Resuming is not
+/// implemented: this never builds a `RowSet`, ignores every argument but `tcb`, and
+/// always fails the task with `ErrorCategory::Unsupported`. Paging-state checkpointing
+/// isn't wired up for the `QueryPager`-based streaming path this wrapper uses - see
+/// `row_set_get_paging_state`.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_query_with_paging_state(
+    tcb: Tcb,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    statement: CSharpStr<'_>,
+    paging_state_ptr: *const u8,
+    paging_state_len: usize,
+) -> BridgedOwnedExclusivePtr<CancellationHandle> {
+    let _ = (session_ptr, statement, paging_state_ptr, paging_state_len);
+
+    BridgedFuture::spawn::<_, RowSet, NotYetSupported>(tcb, async move {
+        Err(NotYetSupported(
+            "resuming a query from a captured paging-state blob",
+        ))
+    })
+}
+
+/// Binds `values` to `prepared` and executes it, like `session_query_with_values`
+/// but reusing the prepared statement's metadata instead of re-parsing the query string.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_execute(
+    tcb: Tcb,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    prepared_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    values_ptr: BridgedOwnedExclusivePtr<PreSerializedValues>,
+) -> BridgedOwnedExclusivePtr<CancellationHandle> {
+    let bridged_session = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+    let bridged_prepared = ArcFFI::cloned_from_ptr(prepared_ptr).unwrap();
+
+    // Take ownership of the pre-serialized values box so we can move it into the async task.
+    let values_box = BoxFFI::from_ptr(values_ptr).expect("non-null PreSerializedValues pointer");
+
+    let value_count = values_box.len();
+    tracing::debug!(value_count, "scheduling prepared statement for execution");
+
+    BridgedFuture::spawn::<_, _, PagerExecutionError>(tcb, async move {
+        tracing::info!("executing prepared statement");
+        let query_pager = bridged_session
+            .inner
+            .execute_iter(bridged_prepared.inner.clone(), &*values_box)
+            .await?;
+        tracing::debug!("prepared statement executed");
+
+        Ok(RowSet {
+            pager: std::sync::Mutex::new(query_pager),
+        })
+    })
 }
@@ -1,16 +1,154 @@
 use futures::FutureExt;
-use std::ffi::{CString, c_char, c_void};
+use std::ffi::{CStr, c_char, c_void};
 use std::fmt::{Debug, Display};
 use std::future::Future;
 use std::panic::AssertUnwindSafe;
-use std::sync::{Arc, LazyLock};
-use tokio::runtime::Runtime;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::runtime::{Builder, Handle, Runtime};
 
 use crate::FfiPtr;
-use crate::ffi::{ArcFFI, BridgedOwnedSharedPtr};
+use crate::error::BridgedError;
+use crate::ffi::{ArcFFI, BoxFFI, BridgedBorrowedExclusivePtr, BridgedOwnedExclusivePtr, BridgedOwnedSharedPtr, FFI, FromBox};
+
+/// State of the global Tokio runtime.
+///
+/// The runtime starts `Uninitialized`. It transitions to `Running` either
+/// explicitly via [`runtime_init`], or implicitly (with default settings) the
+/// first time a future needs to be spawned or blocked on. Once [`runtime_shutdown`]
+/// has shut down a `Running` runtime, it transitions to `ShutDown` and can never be
+/// used again (calling it while `Uninitialized` leaves the state unchanged).
+enum RuntimeState {
+    Uninitialized,
+    Running(Runtime),
+    ShutDown,
+}
 
 /// The global Tokio runtime used to execute async tasks.
-static RUNTIME: LazyLock<Runtime> = LazyLock::new(|| Runtime::new().unwrap());
+static RUNTIME: Mutex<RuntimeState> = Mutex::new(RuntimeState::Uninitialized);
+
+/// Runs `f` with a handle to the global runtime, lazily creating a default one
+/// (matching the previous hardcoded behavior) if [`runtime_init`] was never called.
+///
+/// The global lock is only held long enough to obtain/create the runtime and clone its
+/// `Handle` - it is released before `f` runs. This matters because `f` is typically
+/// `block_on`/`spawn`, which can run for an unbounded time (and `block_on` in particular
+/// can re-enter this crate's FFI surface, e.g. via a deserialization callback into C# that
+/// turns around and calls `session_query`); holding the lock across that would serialize
+/// unrelated concurrent queries on one mutex and deadlock on any such re-entry.
+///
+/// ## Panics
+/// Panics if the runtime has already been shut down via [`runtime_shutdown`].
+fn with_runtime<R>(f: impl FnOnce(&Handle) -> R) -> R {
+    let handle = {
+        let mut state = RUNTIME.lock().unwrap();
+        if matches!(*state, RuntimeState::Uninitialized) {
+            *state = RuntimeState::Running(Runtime::new().expect("failed to create default Tokio runtime"));
+        }
+        match &*state {
+            RuntimeState::Running(rt) => rt.handle().clone(),
+            RuntimeState::ShutDown => panic!("the Tokio runtime has already been shut down"),
+            RuntimeState::Uninitialized => unreachable!("just initialized above"),
+        }
+    };
+    f(&handle)
+}
+
+/// Configuration accepted by [`runtime_init`] for building the global Tokio runtime.
+///
+/// A value of `0` for `worker_threads`/`max_blocking_threads` means "use Tokio's default".
+#[repr(C)]
+pub struct RuntimeConfig {
+    /// Number of worker threads for the multi-thread scheduler. Ignored when `multi_thread` is 0.
+    worker_threads: usize,
+    /// Maximum number of threads spawned for blocking operations.
+    max_blocking_threads: usize,
+    /// Optional prefix for the names of the runtime's threads. May be null to use Tokio's default.
+    thread_name_prefix: *const c_char,
+    /// Non-zero selects the multi-thread scheduler, zero selects the current-thread scheduler.
+    multi_thread: i32,
+}
+
+/// Status codes returned by [`runtime_init`] and [`runtime_shutdown`].
+#[repr(i32)]
+pub enum RuntimeInitResult {
+    Ok = 0,
+    /// The runtime was already initialized (explicitly, or implicitly by prior use).
+    AlreadyInitialized = -1,
+    /// `Builder::build()` failed, e.g. the OS refused to spawn the requested threads.
+    BuildFailed = -2,
+}
+
+/// Builds and installs the global Tokio runtime from the provided configuration.
+///
+/// Must be called before any session is created (i.e. before any other function
+/// in this crate spawns a future or blocks on one). Returns
+/// [`RuntimeInitResult::AlreadyInitialized`] if the runtime was already initialized,
+/// either by a previous call to this function or implicitly by prior use.
+#[unsafe(no_mangle)]
+pub extern "C" fn runtime_init(config: RuntimeConfig) -> i32 {
+    let mut state = RUNTIME.lock().unwrap();
+    if !matches!(*state, RuntimeState::Uninitialized) {
+        return RuntimeInitResult::AlreadyInitialized as i32;
+    }
+
+    let mut builder = if config.multi_thread != 0 {
+        let mut builder = Builder::new_multi_thread();
+        if config.worker_threads > 0 {
+            builder.worker_threads(config.worker_threads);
+        }
+        builder
+    } else {
+        Builder::new_current_thread()
+    };
+
+    if config.max_blocking_threads > 0 {
+        builder.max_blocking_threads(config.max_blocking_threads);
+    }
+
+    if !config.thread_name_prefix.is_null() {
+        // SAFETY: caller guarantees `thread_name_prefix` is either null or a valid,
+        // NUL-terminated C string that outlives this call.
+        if let Ok(prefix) = unsafe { CStr::from_ptr(config.thread_name_prefix) }.to_str() {
+            builder.thread_name(prefix.to_owned());
+        }
+    }
+
+    match builder.enable_all().build() {
+        Ok(runtime) => {
+            *state = RuntimeState::Running(runtime);
+            RuntimeInitResult::Ok as i32
+        }
+        Err(_) => RuntimeInitResult::BuildFailed as i32,
+    }
+}
+
+/// Shuts down the global Tokio runtime, waiting up to `timeout_millis` milliseconds
+/// for in-flight tasks to finish (see [`Runtime::shutdown_timeout`]).
+///
+/// After this call, the runtime can no longer be used - any subsequent attempt to
+/// spawn a future or block on one will panic. Returns [`RuntimeInitResult::AlreadyInitialized`]
+/// (reused here to mean "nothing to shut down") if the runtime was never initialized or
+/// was already shut down.
+///
+/// This function blocks the calling thread, so it must not be called from within a task
+/// running on the runtime itself.
+#[unsafe(no_mangle)]
+pub extern "C" fn runtime_shutdown(timeout_millis: u64) -> i32 {
+    let runtime = {
+        let mut state = RUNTIME.lock().unwrap();
+        match std::mem::replace(&mut *state, RuntimeState::ShutDown) {
+            RuntimeState::Running(runtime) => runtime,
+            other @ (RuntimeState::Uninitialized | RuntimeState::ShutDown) => {
+                *state = other;
+                return RuntimeInitResult::AlreadyInitialized as i32;
+            }
+        }
+    };
+
+    runtime.shutdown_timeout(Duration::from_millis(timeout_millis));
+    RuntimeInitResult::Ok as i32
+}
 
 /// Opaque type representing a C# TaskCompletionSource<T>.
 enum Tcs {}
@@ -24,8 +162,9 @@ unsafe impl Send for TcsPtr {}
 /// Function pointer type to complete a TaskCompletionSource with a result.
 type CompleteTask = unsafe extern "C" fn(tcs: TcsPtr, result: BridgedOwnedSharedPtr<c_void>);
 
-/// Function pointer type to fail a TaskCompletionSource with an error message.
-type FailTask = unsafe extern "C" fn(tcs: TcsPtr, error_msg: *const c_char);
+/// Function pointer type to fail a TaskCompletionSource with a structured error.
+/// The callee takes ownership of `error` and must release it via `bridged_error_free`.
+type FailTask = unsafe extern "C" fn(tcs: TcsPtr, error: *mut BridgedError);
 
 /// **Task Control Block** (TCB)
 ///
@@ -40,6 +179,66 @@ pub struct Tcb {
     fail_task: FailTask,
 }
 
+/// Handle allowing the C# side to cancel a previously spawned bridged future.
+///
+/// Wraps a Tokio [`tokio::task::AbortHandle`]. Aborting a task that has already
+/// completed is a no-op.
+pub struct CancellationHandle {
+    abort_handle: tokio::task::AbortHandle,
+}
+
+impl FFI for CancellationHandle {
+    type Origin = FromBox;
+}
+
+/// Requests cancellation of the task associated with this handle.
+///
+/// If the task has not yet completed, it is dropped at its next await point and the
+/// associated TCS is failed (see [`CompletionGuard`]) rather than completed.
+#[unsafe(no_mangle)]
+pub extern "C" fn cancellation_handle_cancel(
+    handle_ptr: BridgedBorrowedExclusivePtr<'_, CancellationHandle>,
+) {
+    if let Some(handle) = BoxFFI::as_ref(handle_ptr) {
+        handle.abort_handle.abort();
+    }
+}
+
+/// Frees a [`CancellationHandle`] without cancelling the underlying task.
+#[unsafe(no_mangle)]
+pub extern "C" fn cancellation_handle_free(handle_ptr: BridgedOwnedExclusivePtr<CancellationHandle>) {
+    BoxFFI::free(handle_ptr);
+}
+
+/// Ensures that an abandoned (cancelled) task still resolves its TCS instead of leaking it.
+///
+/// When a task is aborted via [`tokio::task::AbortHandle::abort`], the task's future is
+/// simply dropped at its next await point - none of the `Ok`/`Err` branches in [`BridgedFuture::spawn`]
+/// ever run. Without this guard the TCS would never be completed or failed, so the
+/// C# caller's `Task` would hang forever. The guard's `Drop` impl fires only if the task
+/// is torn down before calling `disarm`, i.e. exactly the cancellation case.
+struct CompletionGuard {
+    tcs: Option<TcsPtr>,
+    fail_task: FailTask,
+}
+
+impl CompletionGuard {
+    /// Takes ownership of the TCS pointer, preventing the `Drop` impl from firing.
+    /// Call this once the task has decided how it will resolve the TCS itself.
+    fn disarm(&mut self) -> TcsPtr {
+        self.tcs.take().expect("CompletionGuard::disarm called twice")
+    }
+}
+
+impl Drop for CompletionGuard {
+    fn drop(&mut self) {
+        if let Some(tcs) = self.tcs.take() {
+            let error = Box::new(BridgedError::cancelled());
+            unsafe { (self.fail_task)(tcs, Box::into_raw(error)) };
+        }
+    }
+}
+
 /// A utility struct to bridge Rust tokio futures with C# tasks.
 pub(crate) struct BridgedFuture {
     // For now empty - all methods are static.
@@ -54,7 +253,10 @@ impl BridgedFuture {
     /// If the future panics, the panic is caught and reported as an exception to the C# side.
     /// The future must return a Result, where the Ok variant is sent back to C# on success,
     /// and the Err variant is sent back as an exception message.
-    pub(crate) fn spawn<F, T, E>(tcb: Tcb, future: F)
+    ///
+    /// Returns a [`CancellationHandle`] the caller can use to abort the task - e.g. when the
+    /// C# caller abandons the query via a `CancellationToken` or a timeout.
+    pub(crate) fn spawn<F, T, E>(tcb: Tcb, future: F) -> BridgedOwnedExclusivePtr<CancellationHandle>
     where
         F: Future<Output = Result<T, E>> + Send + 'static,
         T: Send + 'static + ArcFFI, // Must be shareable across FFI boundary. For now we only support ArcFFI.
@@ -67,45 +269,60 @@ impl BridgedFuture {
             fail_task,
         } = tcb;
 
-        RUNTIME.spawn(async move {
-            // Catch panics in the future to prevent unwinding tokio executor thread's stack.
-            let result = AssertUnwindSafe(future).catch_unwind().await;
-
-            // Temporary debug print to help trace issues in async tasks.
-            println!(
-                "Future completed with result: {:?} - {:?}",
-                std::any::type_name::<T>(),
-                result
-            );
-
-            match result {
-                // On success, complete the task with the result.
-                Ok(Ok(res)) => {
-                    let arced_res = Arc::new(res);
-                    unsafe { complete_task(tcs, ArcFFI::into_ptr(arced_res).cast_to_void()) };
-                }
+        // Built before spawning and moved into the task, so a task aborted before its
+        // first poll still drops the guard (failing the TCS) along with its future.
+        let mut guard = CompletionGuard {
+            tcs: Some(tcs),
+            fail_task,
+        };
 
-                // On error, fail the task with the error message.
-                Ok(Err(err)) => {
-                    let error_msg = CString::new(err.to_string()).unwrap();
-                    unsafe { fail_task(tcs, error_msg.as_ptr()) };
-                }
+        let join_handle = with_runtime(|rt| {
+            rt.spawn(async move {
+                // Catch panics in the future to prevent unwinding tokio executor thread's stack.
+                let result = AssertUnwindSafe(future).catch_unwind().await;
+
+                tracing::debug!(
+                    result_type = std::any::type_name::<T>(),
+                    ?result,
+                    "bridged future completed"
+                );
+
+                match result {
+                    // On success, complete the task with the result.
+                    Ok(Ok(res)) => {
+                        let arced_res = Arc::new(res);
+                        let tcs = guard.disarm();
+                        unsafe { complete_task(tcs, ArcFFI::into_ptr(arced_res).cast_to_void()) };
+                    }
 
-                // On panic, fail the task with the panic message.
-                Err(panic) => {
-                    // Panic payloads can be of any type, but `panic!()` macro only uses &str or String.
-                    let panic_msg = if let Some(s) = panic.downcast_ref::<&str>() {
-                        *s
-                    } else if let Some(s) = panic.downcast_ref::<String>() {
-                        s.as_str()
-                    } else {
-                        "Weird panic with non-string payload"
-                    };
-                    let error_msg = CString::new(panic_msg).unwrap();
-                    unsafe { fail_task(tcs, error_msg.as_ptr()) };
+                    // On error, classify the error and fail the task with a structured BridgedError.
+                    Ok(Err(err)) => {
+                        let error = Box::new(BridgedError::from_scylla_error(&err));
+                        let tcs = guard.disarm();
+                        unsafe { fail_task(tcs, Box::into_raw(error)) };
+                    }
+
+                    // On panic, fail the task with the panic message.
+                    Err(panic) => {
+                        // Panic payloads can be of any type, but `panic!()` macro only uses &str or String.
+                        let panic_msg = if let Some(s) = panic.downcast_ref::<&str>() {
+                            *s
+                        } else if let Some(s) = panic.downcast_ref::<String>() {
+                            s.as_str()
+                        } else {
+                            "Weird panic with non-string payload"
+                        };
+                        let error = Box::new(BridgedError::panic(panic_msg));
+                        let tcs = guard.disarm();
+                        unsafe { fail_task(tcs, Box::into_raw(error)) };
+                    }
                 }
-            }
+            })
         });
+
+        BoxFFI::into_ptr(Box::new(CancellationHandle {
+            abort_handle: join_handle.abort_handle(),
+        }))
     }
 
     /// Blocks the current thread until the provided future completes, returning its output.
@@ -114,7 +331,7 @@ impl BridgedFuture {
     /// Although it's inherently inefficient, it's not our choice - the C# Driver's blocking API is what it is.
     /// Use with caution and prefer async APIs whenever possible.
     pub(crate) fn block_on<T>(future: impl Future<Output = T>) -> T {
-        RUNTIME.block_on(future)
+        with_runtime(|rt| rt.block_on(future))
     }
 }
 
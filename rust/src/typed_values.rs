@@ -0,0 +1,294 @@
+//! Typed value builder: a parallel alternative to [`crate::pre_serialized_values::PreSerializedValues`]
+//! that accepts native scalars and converts them to the CQL wire format on the Rust side,
+//! instead of requiring the caller to hand over already-serialized bytes.
+
+use scylla::serialize::row::{RowSerializationContext, SerializeRow};
+use scylla::serialize::writers::RowWriter;
+use scylla::serialize::SerializationError;
+
+use crate::ffi::{BoxFFI, BridgedBorrowedExclusivePtr, BridgedOwnedExclusivePtr, FFI, FromBox};
+use crate::pre_serialized_values::{serialize_each_cell, validate_number_of_columns, HasCells};
+
+/// A single value, already converted to the CQL wire format (or null/unset).
+///
+/// Unlike `PreSerializedValues`, the conversion from a native scalar to its
+/// wire-format bytes happens eagerly when the value is added, so by the time
+/// `serialize` runs every cell is already a plain byte buffer (or null/unset).
+#[derive(Debug, Clone)]
+enum Conversion {
+    Bytes(Vec<u8>),
+    Null,
+    Unset,
+}
+
+/// Builder that accepts native scalars and converts them to CQL cells, implementing
+/// [`SerializeRow`] so it can be passed wherever a pre-serialized row is expected.
+#[derive(Debug, Default)]
+pub struct TypedValues {
+    cells: Vec<Conversion>,
+}
+
+impl FFI for TypedValues {
+    type Origin = FromBox;
+}
+
+impl TypedValues {
+    fn new() -> Self {
+        Self { cells: Vec::new() }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl HasCells<Conversion> for TypedValues {
+    fn get_cells(&self) -> &Vec<Conversion> {
+        &self.cells
+    }
+}
+
+impl SerializeRow for TypedValues {
+    fn serialize(
+        &self,
+        ctx: &RowSerializationContext<'_>,
+        writer: &mut RowWriter,
+    ) -> Result<(), SerializationError> {
+        validate_number_of_columns(self.len(), ctx)?;
+        serialize_each_cell::<Conversion, _>(self, ctx, writer, |cw, cell| match cell {
+            Conversion::Bytes(bytes) => cw
+                .set_value(bytes)
+                .map(|_proof| ())
+                .map_err(SerializationError::new),
+            Conversion::Null => {
+                let _ = cw.set_null();
+                Ok(())
+            }
+            Conversion::Unset => {
+                let _ = cw.set_unset();
+                Ok(())
+            }
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+// ===== FFI exported functions =====
+
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_new() -> BridgedOwnedExclusivePtr<TypedValues> {
+    BoxFFI::into_ptr(Box::new(TypedValues::new()))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_free(values_ptr: BridgedOwnedExclusivePtr<TypedValues>) {
+    BoxFFI::free(values_ptr);
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_null(values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        values.cells.push(Conversion::Null);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_unset(values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        values.cells.push(Conversion::Unset);
+    }
+}
+
+/// Appends a CQL `int` (32-bit signed big-endian).
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_int32(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    value: i32,
+) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        values
+            .cells
+            .push(Conversion::Bytes(value.to_be_bytes().to_vec()));
+    }
+}
+
+/// Appends a CQL `bigint` (64-bit signed big-endian).
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_int64(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    value: i64,
+) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        values
+            .cells
+            .push(Conversion::Bytes(value.to_be_bytes().to_vec()));
+    }
+}
+
+/// Appends a CQL `varint`. The caller supplies the value already encoded as a
+/// minimal two's-complement big-endian byte sequence.
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_varint(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    data: *const u8,
+    len: usize,
+) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        let bytes = unsafe { std::slice::from_raw_parts(data, len) }.to_vec();
+        values.cells.push(Conversion::Bytes(bytes));
+    }
+}
+
+/// Appends a CQL `float` (32-bit IEEE754 big-endian).
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_float(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    value: f32,
+) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        values
+            .cells
+            .push(Conversion::Bytes(value.to_be_bytes().to_vec()));
+    }
+}
+
+/// Appends a CQL `double` (64-bit IEEE754 big-endian).
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_double(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    value: f64,
+) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        values
+            .cells
+            .push(Conversion::Bytes(value.to_be_bytes().to_vec()));
+    }
+}
+
+/// Appends a CQL `boolean` (single byte, 0x00 or 0x01).
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_bool(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    value: i32,
+) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        values
+            .cells
+            .push(Conversion::Bytes(vec![if value != 0 { 1 } else { 0 }]));
+    }
+}
+
+/// Appends a CQL `text`/`varchar` value. `data` must point to `len` bytes of UTF-8.
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_text(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    data: *const u8,
+    len: usize,
+) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        let bytes = unsafe { std::slice::from_raw_parts(data, len) }.to_vec();
+        values.cells.push(Conversion::Bytes(bytes));
+    }
+}
+
+/// Appends a CQL `ascii` value. Encoded identically to `text` on the wire.
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_ascii(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    data: *const u8,
+    len: usize,
+) {
+    typed_values_add_text(values_ptr, data, len);
+}
+
+/// Appends a CQL `blob` value.
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_blob(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    data: *const u8,
+    len: usize,
+) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        let bytes = unsafe { std::slice::from_raw_parts(data, len) }.to_vec();
+        values.cells.push(Conversion::Bytes(bytes));
+    }
+}
+
+/// Appends a CQL `uuid`/`timeuuid` value. `data` must point to exactly 16 bytes.
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_uuid(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    data: *const u8,
+    len: usize,
+) -> i32 {
+    if len != 16 {
+        return 0;
+    }
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        let bytes = unsafe { std::slice::from_raw_parts(data, len) }.to_vec();
+        values.cells.push(Conversion::Bytes(bytes));
+        1
+    } else {
+        0
+    }
+}
+
+/// Appends a CQL `timestamp` from an epoch-milliseconds fast path.
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_timestamp_millis(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    millis: i64,
+) {
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        values
+            .cells
+            .push(Conversion::Bytes(millis.to_be_bytes().to_vec()));
+    }
+}
+
+/// Appends a CQL `timestamp`, parsing `date_str` according to the strftime-style `fmt`
+/// pattern and emitting the resulting epoch-millisecond value.
+///
+/// Returns 1 on success, 0 if either string is not valid UTF-8 or does not match `fmt`.
+#[unsafe(no_mangle)]
+pub extern "C" fn typed_values_add_timestamp_fmt(
+    values_ptr: BridgedBorrowedExclusivePtr<'_, TypedValues>,
+    date_ptr: *const u8,
+    date_len: usize,
+    fmt_ptr: *const u8,
+    fmt_len: usize,
+) -> i32 {
+    let date_str = match std::str::from_utf8(unsafe { std::slice::from_raw_parts(date_ptr, date_len) }) {
+        Ok(s) => s,
+        Err(_) => return 0,
+    };
+    let fmt = match std::str::from_utf8(unsafe { std::slice::from_raw_parts(fmt_ptr, fmt_len) }) {
+        Ok(s) => s,
+        Err(_) => return 0,
+    };
+
+    // Interpreted in the local timezone, matching the equivalent `TimestampFmt` conversion
+    // on `PreSerializedValues` - the format string carries no UTC offset, so the caller is
+    // expected to pass wall-clock-local dates.
+    let millis = match chrono::NaiveDateTime::parse_from_str(date_str, fmt)
+        .ok()
+        .and_then(|naive| {
+            use chrono::TimeZone;
+            chrono::Local.from_local_datetime(&naive).single()
+        }) {
+        Some(dt) => dt.timestamp_millis(),
+        None => return 0,
+    };
+
+    if let Some(values) = BoxFFI::as_mut_ref(values_ptr) {
+        values
+            .cells
+            .push(Conversion::Bytes(millis.to_be_bytes().to_vec()));
+        1
+    } else {
+        0
+    }
+}